@@ -0,0 +1,55 @@
+//! Tokenizer throughput.
+//!
+//! Tokenizes inputs of doubling size and reports the time per token and
+//! per byte, which should stay roughly constant if tokenization is linear.
+//!
+//!   cargo bench --bench tokenizer
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use recursive_descent_parser::Tokenizer;
+
+const STATEMENT: &str = "// comment\n{ (42 + 7) * \"hello\" / 3 - 1; }\n";
+
+/// Every `/*` is unterminated, so a tokenizer that searches ahead for
+/// the closing `*/` from each of them is quadratic.
+const UNTERMINATED_COMMENT: &str = "/* ";
+
+fn main() {
+    bench("well-formed", STATEMENT, 1_000, 6);
+    bench("unterminated comments", UNTERMINATED_COMMENT, 10_000, 4);
+}
+
+/// Tokenizes `unit` repeated `repeat`, `2 * repeat`, ... times, counting
+/// lexical errors as tokens.
+fn bench(name: &str, unit: &str, repeat: usize, sizes: usize) {
+    println!("{}", name);
+    println!(
+        "{:>12} {:>10} {:>12} {:>10} {:>10}",
+        "bytes", "tokens", "total", "ns/token", "ns/byte"
+    );
+
+    for power in 0..sizes {
+        let source = unit.repeat(repeat << power);
+        let bytes = source.len();
+
+        let started = Instant::now();
+        let mut tokens = 0;
+        for token in Tokenizer::new(source) {
+            black_box(token).ok();
+            tokens += 1;
+        }
+        let elapsed = started.elapsed();
+
+        println!(
+            "{:>12} {:>10} {:>12?} {:>10.1} {:>10.2}",
+            bytes,
+            tokens,
+            elapsed,
+            elapsed.as_nanos() as f64 / tokens as f64,
+            elapsed.as_nanos() as f64 / bytes as f64
+        );
+    }
+    println!();
+}
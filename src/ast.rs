@@ -0,0 +1,418 @@
+//! Abstract Syntax Tree.
+//!
+//! Node kinds follow the ESTree naming used in the grammar comments
+//! of the parser.
+
+use std::fmt;
+
+use crate::tokenizer::Span;
+
+/// Root node of a parsed source.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Program {
+    pub body: Vec<Statement>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statement {
+    pub kind: StatementKind,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StatementKind {
+    ExpressionStatement { expression: Expression },
+    BlockStatement { body: Vec<Statement> },
+    /// A lone `;`.
+    EmptyStatement,
+    VariableDeclaration {
+        kind: VariableKind,
+        declarations: Vec<VariableDeclarator>,
+    },
+    IfStatement {
+        test: Expression,
+        consequent: Box<Statement>,
+        alternate: Option<Box<Statement>>,
+    },
+    WhileStatement {
+        test: Expression,
+        body: Box<Statement>,
+    },
+    DoWhileStatement {
+        body: Box<Statement>,
+        test: Expression,
+    },
+    ForStatement {
+        init: Option<ForInit>,
+        test: Option<Expression>,
+        update: Option<Expression>,
+        body: Box<Statement>,
+    },
+    FunctionDeclaration {
+        id: Identifier,
+        params: Vec<Identifier>,
+        /// Always a `StatementKind::BlockStatement`.
+        body: Box<Statement>,
+    },
+    ClassDeclaration {
+        id: Identifier,
+        super_class: Option<Expression>,
+        body: Vec<MethodDefinition>,
+    },
+    ReturnStatement {
+        argument: Option<Expression>,
+    },
+    /// Input skipped during error recovery; the parser diagnostics
+    /// describe what was wrong with it.
+    Error,
+}
+
+/// First clause of a `for` statement.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ForInit {
+    /// Always a `StatementKind::VariableDeclaration`.
+    VariableDeclaration(Box<Statement>),
+    Expression(Expression),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VariableKind {
+    Let,
+    Const,
+    Var,
+}
+
+/// `id = init` part of a variable declaration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariableDeclarator {
+    pub id: Identifier,
+    pub init: Option<Expression>,
+    pub span: Span,
+}
+
+/// Method in a class body.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MethodDefinition {
+    pub key: Identifier,
+    pub kind: MethodKind,
+    pub params: Vec<Identifier>,
+    /// Always a `StatementKind::BlockStatement`.
+    pub body: Box<Statement>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MethodKind {
+    /// The method named `constructor`.
+    Constructor,
+    Method,
+}
+
+/// Body of an arrow function.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArrowBody {
+    /// Always a `StatementKind::BlockStatement`.
+    Block(Box<Statement>),
+    /// Concise body, whose value is returned.
+    Expression(Box<Expression>),
+}
+
+/// `key: value` entry of an object literal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Property {
+    /// Identifier, string or number, or any expression when `computed`.
+    pub key: Expression,
+    pub value: Expression,
+    /// Whether the key was written as `[key]`.
+    pub computed: bool,
+    pub span: Span,
+}
+
+/// Name in binding position, e.g. the declared variable.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Identifier {
+    pub name: String,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expression {
+    pub kind: ExpressionKind,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExpressionKind {
+    Identifier {
+        name: String,
+    },
+    NumericLiteral {
+        /// Source text, e.g. `0xFF` or `1_000`.
+        raw: String,
+        value: f64,
+    },
+    StringLiteral {
+        value: String,
+    },
+    BooleanLiteral {
+        value: bool,
+    },
+    NullLiteral,
+    /// `[a, , b]`; holes are `None`.
+    ArrayExpression {
+        elements: Vec<Option<Expression>>,
+    },
+    ObjectExpression {
+        properties: Vec<Property>,
+    },
+    BinaryExpression {
+        operator: BinaryOp,
+        left: Box<Expression>,
+        right: Box<Expression>,
+    },
+    /// Short-circuiting `&&` / `||`, kept apart from `BinaryExpression`.
+    LogicalExpression {
+        operator: LogicalOp,
+        left: Box<Expression>,
+        right: Box<Expression>,
+    },
+    UnaryExpression {
+        operator: UnaryOp,
+        argument: Box<Expression>,
+    },
+    AssignmentExpression {
+        operator: AssignmentOp,
+        left: Box<Expression>,
+        right: Box<Expression>,
+    },
+    /// `test ? consequent : alternate`.
+    ConditionalExpression {
+        test: Box<Expression>,
+        consequent: Box<Expression>,
+        alternate: Box<Expression>,
+    },
+    /// `object.property` or, when `computed`, `object[property]`.
+    MemberExpression {
+        object: Box<Expression>,
+        property: Box<Expression>,
+        computed: bool,
+    },
+    CallExpression {
+        callee: Box<Expression>,
+        arguments: Vec<Expression>,
+    },
+    /// `new callee(arguments)`; the parentheses are optional.
+    NewExpression {
+        callee: Box<Expression>,
+        arguments: Vec<Expression>,
+    },
+    /// `function name(params) { ... }` in expression position; the name
+    /// is optional.
+    FunctionExpression {
+        id: Option<Identifier>,
+        params: Vec<Identifier>,
+        /// Always a `StatementKind::BlockStatement`.
+        body: Box<Statement>,
+    },
+    /// `(params) => body` or `param => body`.
+    ArrowFunctionExpression {
+        params: Vec<Identifier>,
+        body: ArrowBody,
+    },
+    ThisExpression,
+    /// `super`, only ever the callee of a call or the object of a member
+    /// expression.
+    Super,
+    /// Input that failed to lex; the parser diagnostics describe what was
+    /// wrong with it.
+    Error,
+}
+
+impl Expression {
+    /// Whether the expression may appear on the left of an assignment.
+    pub fn is_assignment_target(&self) -> bool {
+        matches!(
+            self.kind,
+            ExpressionKind::Identifier { .. } | ExpressionKind::MemberExpression { .. }
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    LessThan,
+    GreaterThan,
+    LessThanOrEqual,
+    GreaterThanOrEqual,
+    Equal,
+    NotEqual,
+    StrictEqual,
+    StrictNotEqual,
+    /// Operator registered with `Parser::with_operators`, by source text.
+    Custom(&'static str),
+}
+
+impl BinaryOp {
+    /// Operator for the given source text, if any.
+    pub fn from_operator(string: &str) -> Option<Self> {
+        match string {
+            "+" => Some(BinaryOp::Add),
+            "-" => Some(BinaryOp::Subtract),
+            "*" => Some(BinaryOp::Multiply),
+            "/" => Some(BinaryOp::Divide),
+            "<" => Some(BinaryOp::LessThan),
+            ">" => Some(BinaryOp::GreaterThan),
+            "<=" => Some(BinaryOp::LessThanOrEqual),
+            ">=" => Some(BinaryOp::GreaterThanOrEqual),
+            "==" => Some(BinaryOp::Equal),
+            "!=" => Some(BinaryOp::NotEqual),
+            "===" => Some(BinaryOp::StrictEqual),
+            "!==" => Some(BinaryOp::StrictNotEqual),
+            _ => None,
+        }
+    }
+
+    /// Source text of the operator.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BinaryOp::Add => "+",
+            BinaryOp::Subtract => "-",
+            BinaryOp::Multiply => "*",
+            BinaryOp::Divide => "/",
+            BinaryOp::LessThan => "<",
+            BinaryOp::GreaterThan => ">",
+            BinaryOp::LessThanOrEqual => "<=",
+            BinaryOp::GreaterThanOrEqual => ">=",
+            BinaryOp::Equal => "==",
+            BinaryOp::NotEqual => "!=",
+            BinaryOp::StrictEqual => "===",
+            BinaryOp::StrictNotEqual => "!==",
+            BinaryOp::Custom(symbol) => symbol,
+        }
+    }
+}
+
+impl fmt::Display for BinaryOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogicalOp {
+    And,
+    Or,
+}
+
+impl LogicalOp {
+    /// Operator for the given source text, if any.
+    pub fn from_operator(string: &str) -> Option<Self> {
+        match string {
+            "&&" => Some(LogicalOp::And),
+            "||" => Some(LogicalOp::Or),
+            _ => None,
+        }
+    }
+
+    /// Source text of the operator.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogicalOp::And => "&&",
+            LogicalOp::Or => "||",
+        }
+    }
+}
+
+impl fmt::Display for LogicalOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnaryOp {
+    Minus,
+    Plus,
+    Not,
+    Typeof,
+    Void,
+    Delete,
+}
+
+impl UnaryOp {
+    /// Operator for the given source text, if any.
+    pub fn from_operator(string: &str) -> Option<Self> {
+        match string {
+            "-" => Some(UnaryOp::Minus),
+            "+" => Some(UnaryOp::Plus),
+            "!" => Some(UnaryOp::Not),
+            "typeof" => Some(UnaryOp::Typeof),
+            "void" => Some(UnaryOp::Void),
+            "delete" => Some(UnaryOp::Delete),
+            _ => None,
+        }
+    }
+
+    /// Source text of the operator.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UnaryOp::Minus => "-",
+            UnaryOp::Plus => "+",
+            UnaryOp::Not => "!",
+            UnaryOp::Typeof => "typeof",
+            UnaryOp::Void => "void",
+            UnaryOp::Delete => "delete",
+        }
+    }
+}
+
+impl fmt::Display for UnaryOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssignmentOp {
+    Assign,
+    AddAssign,
+    SubtractAssign,
+    MultiplyAssign,
+    DivideAssign,
+}
+
+impl AssignmentOp {
+    /// Operator for the given source text, if any.
+    pub fn from_operator(string: &str) -> Option<Self> {
+        match string {
+            "=" => Some(AssignmentOp::Assign),
+            "+=" => Some(AssignmentOp::AddAssign),
+            "-=" => Some(AssignmentOp::SubtractAssign),
+            "*=" => Some(AssignmentOp::MultiplyAssign),
+            "/=" => Some(AssignmentOp::DivideAssign),
+            _ => None,
+        }
+    }
+
+    /// Source text of the operator.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AssignmentOp::Assign => "=",
+            AssignmentOp::AddAssign => "+=",
+            AssignmentOp::SubtractAssign => "-=",
+            AssignmentOp::MultiplyAssign => "*=",
+            AssignmentOp::DivideAssign => "/=",
+        }
+    }
+}
+
+impl fmt::Display for AssignmentOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,213 @@
+//! Diagnostic rendering.
+//!
+//! Formats errors the way compilers usually do: a headline, the location,
+//! the offending source line with the span underlined, and optional notes.
+//!
+//! ```text
+//! error: expected ';' but found ')'
+//!  --> main.js:1:6
+//!   |
+//! 1 | (42) )
+//!   |      ^
+//! ```
+
+use crate::error::{LexError, ParseError};
+use crate::tokenizer::Span;
+
+const RED: &str = "\x1b[1;31m";
+const BLUE: &str = "\x1b[1;34m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub message: String,
+    pub span: Span,
+    pub notes: Vec<String>,
+}
+
+impl Diagnostic {
+    pub fn new(message: String, span: Span) -> Self {
+        Self {
+            message,
+            span,
+            notes: vec![],
+        }
+    }
+
+    /// Adds a note printed below the source snippet.
+    pub fn with_note(mut self, note: String) -> Self {
+        self.notes.push(note);
+        self
+    }
+
+    /// Renders the diagnostic against `source`, using ANSI colors if `color` is set.
+    pub fn render(&self, file_name: &str, source: &str, color: bool) -> String {
+        let paint = |style: &str, text: &str| {
+            if color {
+                format!("{}{}{}", style, text, RESET)
+            } else {
+                text.to_string()
+            }
+        };
+
+        let line_number = self.span.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+        let line = source.lines().nth(self.span.line.saturating_sub(1)).unwrap_or("");
+
+        // Keep tabs in the padding so the carets line up with the source.
+        let padding: String = line
+            .chars()
+            .take(self.span.column.saturating_sub(1))
+            .map(|character| if character == '\t' { '\t' } else { ' ' })
+            .collect();
+        let line_start = source
+            .get(..self.span.start)
+            .and_then(|before| before.rfind('\n'))
+            .map_or(0, |index| index + 1);
+        let underline = source
+            .get(self.span.start..self.span.end.min(line_start + line.len()))
+            .map_or(0, |text| text.chars().count())
+            .max(1);
+
+        let mut rendered = format!(
+            "{}{}\n",
+            paint(RED, "error"),
+            paint(BOLD, &format!(": {}", self.message))
+        );
+        rendered += &format!(
+            "{}{} {}:{}:{}\n",
+            gutter,
+            paint(BLUE, "-->"),
+            file_name,
+            self.span.line,
+            self.span.column
+        );
+        rendered += &format!("{} {}\n", gutter, paint(BLUE, "|"));
+        rendered += &format!("{} {}\n", paint(BLUE, &format!("{} |", line_number)), line);
+        rendered += &format!(
+            "{} {} {}{}\n",
+            gutter,
+            paint(BLUE, "|"),
+            padding,
+            paint(RED, &"^".repeat(underline))
+        );
+        for note in &self.notes {
+            rendered += &format!("{} {} {}\n", gutter, paint(BLUE, "="), note);
+        }
+
+        rendered
+    }
+}
+
+impl From<&ParseError> for Diagnostic {
+    fn from(err: &ParseError) -> Self {
+        Diagnostic::new(err.message(), err.location)
+    }
+}
+
+impl From<&LexError> for Diagnostic {
+    fn from(err: &LexError) -> Self {
+        Diagnostic::new(err.message(), err.location)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diagnostic::*;
+    use crate::parser::Parser;
+
+    #[test]
+    fn test_render_plain() {
+        let source = "1;\n(42) )\n";
+        let err = Parser::new().parse(String::from(source)).unwrap_err();
+
+        let rendered = Diagnostic::from(&err)
+            .with_note(String::from("statements end with ';'"))
+            .render("main.js", source, false);
+
+        assert_eq!(
+            rendered,
+            "error: expected ';' but found ')'\n \
+             --> main.js:2:6\n  \
+             |\n\
+             2 | (42) )\n  \
+             |      ^\n  \
+             = statements end with ';'\n"
+        );
+    }
+
+    #[test]
+    fn test_render_end_of_input() {
+        let source = "{ \"hello\";";
+        let err = Parser::new().parse(String::from(source)).unwrap_err();
+
+        let rendered = Diagnostic::from(&err).render("main.js", source, false);
+
+        assert_eq!(
+            rendered,
+            "error: expected '}' but found end of input\n \
+             --> main.js:1:11\n  \
+             |\n\
+             1 | { \"hello\";\n  \
+             |           ^\n"
+        );
+    }
+
+    #[test]
+    fn test_render_expected_expression() {
+        let source = "x = ;";
+        let err = Parser::new().parse(String::from(source)).unwrap_err();
+
+        let rendered = Diagnostic::from(&err).render("main.js", source, false);
+
+        assert_eq!(
+            rendered,
+            "error: expected expression but found ';'\n \
+             --> main.js:1:5\n  \
+             |\n\
+             1 | x = ;\n  \
+             |     ^\n"
+        );
+    }
+
+    #[test]
+    fn test_render_span_outside_source() {
+        let diagnostic = Diagnostic::new(String::from("oops"), Span::default());
+        assert_eq!(
+            diagnostic.render("main.js", "", false),
+            "error: oops\n \
+             --> main.js:0:0\n  \
+             |\n\
+             0 | \n  \
+             | ^\n"
+        );
+
+        let diagnostic = Diagnostic::new(String::from("oops"), Span::new(40, 42, 3, 1));
+        assert_eq!(
+            diagnostic.render("main.js", "1;\n", false),
+            "error: oops\n \
+             --> main.js:3:1\n  \
+             |\n\
+             3 | \n  \
+             | ^\n"
+        );
+    }
+
+    #[test]
+    fn test_render_color() {
+        let source = "\t2 + @;";
+        let err = Parser::new().parse(String::from(source)).unwrap_err();
+
+        let rendered = Diagnostic::from(&err).render("main.js", source, true);
+
+        assert_eq!(
+            rendered,
+            "\x1b[1;31merror\x1b[0m\x1b[1m: unknown character '@'\x1b[0m\n \
+             \x1b[1;34m-->\x1b[0m main.js:1:6\n  \
+             \x1b[1;34m|\x1b[0m\n\
+             \x1b[1;34m1 |\x1b[0m \t2 + @;\n  \
+             \x1b[1;34m|\x1b[0m \t    \x1b[1;31m^\x1b[0m\n"
+        );
+    }
+}
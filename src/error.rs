@@ -0,0 +1,140 @@
+//! Lexical and parse errors.
+
+use std::fmt;
+
+use crate::tokenizer::{Span, TokenKind};
+
+/// What went wrong while tokenizing.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LexErrorKind {
+    /// No token in the tokenizer spec matches at this character.
+    UnknownCharacter(char),
+    /// A numeric literal runs into further digits, letters or dots, e.g.
+    /// `1..2` or `0x`.
+    MalformedNumber(String),
+    /// A `/*` comment is never closed; it covers the rest of the input.
+    UnterminatedComment,
+    /// A string literal is never closed; it covers the rest of the input.
+    UnterminatedString,
+}
+
+/// Error produced by the tokenizer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LexError {
+    pub kind: LexErrorKind,
+    /// Where in the source the error occurred.
+    pub location: Span,
+}
+
+impl LexError {
+    /// Human-readable description, without the location.
+    pub fn message(&self) -> String {
+        match self.kind {
+            LexErrorKind::UnknownCharacter(character) => {
+                format!("unknown character {:?}", character)
+            }
+            LexErrorKind::MalformedNumber(ref text) => {
+                format!("malformed numeric literal '{}'", text)
+            }
+            LexErrorKind::UnterminatedComment => String::from("unterminated comment"),
+            LexErrorKind::UnterminatedString => String::from("unterminated string literal"),
+        }
+    }
+}
+
+impl fmt::Display for LexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}:{}", self.message(), self.location.line, self.location.column)
+    }
+}
+
+impl std::error::Error for LexError {}
+
+/// What went wrong while parsing.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseErrorKind {
+    /// A token was found where none of the expected tokens were allowed.
+    UnexpectedToken(String),
+    /// The input ended while more tokens were expected.
+    UnexpectedEof,
+    /// No expression starts at this token; holds the token found, or
+    /// `None` at the end of input.
+    ExpectedExpression(Option<String>),
+    /// The tokenizer could not produce a token.
+    Lex(LexErrorKind),
+    /// The left-hand side of an assignment is not an identifier or member expression.
+    InvalidAssignmentTarget,
+    /// A `return` statement appeared outside of a function body.
+    ReturnOutsideFunction,
+    /// Statements or expressions are nested too deeply to parse.
+    NestingTooDeep,
+}
+
+/// Error produced by the parser, including lexical errors it ran into.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    /// Tokens that would have been accepted instead.
+    pub expected: Vec<TokenKind>,
+    /// Where in the source the error occurred.
+    pub location: Span,
+}
+
+impl ParseError {
+    /// Human-readable description, without the location, e.g.
+    /// "expected ';' but found ')'".
+    pub fn message(&self) -> String {
+        let found = match self.kind {
+            ParseErrorKind::UnexpectedToken(ref token)
+            | ParseErrorKind::ExpectedExpression(Some(ref token)) => format!("'{}'", token),
+            ParseErrorKind::UnexpectedEof | ParseErrorKind::ExpectedExpression(None) => {
+                String::from("end of input")
+            }
+            ParseErrorKind::Lex(ref kind) => {
+                let err = LexError {
+                    kind: kind.clone(),
+                    location: self.location,
+                };
+                return err.message();
+            }
+            ParseErrorKind::InvalidAssignmentTarget => {
+                return String::from("invalid left-hand side in assignment");
+            }
+            ParseErrorKind::ReturnOutsideFunction => {
+                return String::from("'return' outside of function");
+            }
+            ParseErrorKind::NestingTooDeep => return String::from("nesting too deep"),
+        };
+
+        if let ParseErrorKind::ExpectedExpression(_) = self.kind {
+            return format!("expected expression but found {}", found);
+        }
+
+        match self.expected.split_last() {
+            None => format!("unexpected {}", found),
+            Some((last, [])) => format!("expected {} but found {}", last, found),
+            Some((last, rest)) => {
+                let rest: Vec<String> = rest.iter().map(|kind| kind.to_string()).collect();
+                format!("expected {} or {} but found {}", rest.join(", "), last, found)
+            }
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}:{}", self.message(), self.location.line, self.location.column)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<LexError> for ParseError {
+    fn from(err: LexError) -> Self {
+        ParseError {
+            kind: ParseErrorKind::Lex(err.kind),
+            expected: vec![],
+            location: err.location,
+        }
+    }
+}
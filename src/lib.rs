@@ -0,0 +1,23 @@
+//! Recursive Descent Parser.
+//!
+//! Exposes the tokenizer and parser as a library, along with a
+//! convenience `parse` entry point.
+
+pub mod ast;
+pub mod diagnostic;
+pub mod error;
+pub mod operator;
+pub mod parser;
+pub mod tokenizer;
+
+pub use ast::Program;
+pub use diagnostic::Diagnostic;
+pub use error::{LexError, LexErrorKind, ParseError, ParseErrorKind};
+pub use operator::{Associativity, InfixOperator};
+pub use parser::Parser;
+pub use tokenizer::{tokenize, Span, Token, TokenKind, Tokenizer};
+
+/// Parses a string into an AST.
+pub fn parse(string: &str) -> Result<Program, ParseError> {
+    Parser::new().parse(string.to_string())
+}
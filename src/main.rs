@@ -1,6 +1,85 @@
-mod parser;
-mod tokenizer;
+use std::io::{IsTerminal, Read};
+use std::{env, fs, io, process};
+
+use recursive_descent_parser::{Diagnostic, Parser, Tokenizer};
+
+const USAGE: &str = "Usage: recursive-descent-parser [--tokens] [path]";
 
 fn main() {
-    println!("Hello, world!");
+    let mut dump_tokens = false;
+    let mut path = None;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--tokens" => dump_tokens = true,
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return;
+            }
+            _ if path.is_none() && !arg.starts_with("--") => path = Some(arg),
+            _ => {
+                eprintln!("{}", USAGE);
+                process::exit(2);
+            }
+        }
+    }
+
+    let file_name = path.clone().unwrap_or_else(|| String::from("<stdin>"));
+    let source = match path {
+        Some(path) => fs::read_to_string(&path).unwrap_or_else(|err| {
+            eprintln!("Could not read '{}': {}", path, err);
+            process::exit(1);
+        }),
+        None => {
+            let mut source = String::new();
+            if let Err(err) = io::stdin().read_to_string(&mut source) {
+                eprintln!("Could not read stdin: {}", err);
+                process::exit(1);
+            }
+            source
+        }
+    };
+
+    let color = io::stderr().is_terminal() && env::var_os("NO_COLOR").is_none();
+
+    if dump_tokens {
+        print_tokens(&file_name, source, color);
+        return;
+    }
+
+    let (ast, diagnostics) = Parser::new().parse_with_diagnostics(source.clone());
+    if diagnostics.is_empty() {
+        println!("{:#?}", ast);
+        return;
+    }
+
+    for err in &diagnostics {
+        eprintln!("{}", Diagnostic::from(err).render(&file_name, &source, color));
+    }
+    process::exit(1);
+}
+
+/// Prints one line per token: position, kind, text and byte range.
+fn print_tokens(file_name: &str, source: String, color: bool) {
+    let mut failed = false;
+    for token in Tokenizer::new(source.clone()) {
+        match token {
+            Ok(token) => println!(
+                "{}:{}\t{:?}\t{:?}\t{}..{}",
+                token.span.line,
+                token.span.column,
+                token.kind,
+                token.value,
+                token.span.start,
+                token.span.end
+            ),
+            Err(err) => {
+                eprintln!("{}", Diagnostic::from(&err).render(file_name, &source, color));
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,82 @@
+//! Infix operator table.
+//!
+//! Binary and logical expressions are parsed by precedence climbing over
+//! this table, so adding an operator is a matter of adding an entry here
+//! (or registering one with `Parser::with_operators`) rather than writing
+//! a new grammar production.
+
+use crate::ast::{BinaryOp, LogicalOp};
+
+/// Which side an operator groups towards when chained.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Associativity {
+    /// `a - b - c` is `(a - b) - c`.
+    Left,
+    /// `a ** b ** c` is `a ** (b ** c)`.
+    Right,
+}
+
+/// AST node an infix operator produces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InfixKind {
+    Binary(BinaryOp),
+    Logical(LogicalOp),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InfixOperator {
+    /// Source text of the operator.
+    pub symbol: &'static str,
+    /// Binding power; higher binds tighter.
+    pub precedence: u8,
+    pub associativity: Associativity,
+    pub kind: InfixKind,
+}
+
+impl InfixOperator {
+    /// Operator producing a `BinaryExpression` with `BinaryOp::Custom(symbol)`.
+    pub const fn custom(symbol: &'static str, precedence: u8, associativity: Associativity) -> Self {
+        Self {
+            symbol,
+            precedence,
+            associativity,
+            kind: InfixKind::Binary(BinaryOp::Custom(symbol)),
+        }
+    }
+
+    const fn binary(symbol: &'static str, precedence: u8, operator: BinaryOp) -> Self {
+        Self {
+            symbol,
+            precedence,
+            associativity: Associativity::Left,
+            kind: InfixKind::Binary(operator),
+        }
+    }
+
+    const fn logical(symbol: &'static str, precedence: u8, operator: LogicalOp) -> Self {
+        Self {
+            symbol,
+            precedence,
+            associativity: Associativity::Left,
+            kind: InfixKind::Logical(operator),
+        }
+    }
+}
+
+/// Built-in operators, from loosest to tightest binding.
+pub const OPERATORS: [InfixOperator; 14] = [
+    InfixOperator::logical("||", 1, LogicalOp::Or),
+    InfixOperator::logical("&&", 2, LogicalOp::And),
+    InfixOperator::binary("==", 3, BinaryOp::Equal),
+    InfixOperator::binary("!=", 3, BinaryOp::NotEqual),
+    InfixOperator::binary("===", 3, BinaryOp::StrictEqual),
+    InfixOperator::binary("!==", 3, BinaryOp::StrictNotEqual),
+    InfixOperator::binary("<", 4, BinaryOp::LessThan),
+    InfixOperator::binary(">", 4, BinaryOp::GreaterThan),
+    InfixOperator::binary("<=", 4, BinaryOp::LessThanOrEqual),
+    InfixOperator::binary(">=", 4, BinaryOp::GreaterThanOrEqual),
+    InfixOperator::binary("+", 5, BinaryOp::Add),
+    InfixOperator::binary("-", 5, BinaryOp::Subtract),
+    InfixOperator::binary("*", 6, BinaryOp::Multiply),
+    InfixOperator::binary("/", 6, BinaryOp::Divide),
+];
@@ -1,612 +1,4239 @@
-/// Recursive Descent Parser.
-use crate::tokenizer::Tokenizer;
+//! Recursive Descent Parser.
 
-struct Parser {
+use crate::ast::{
+    ArrowBody, AssignmentOp, Expression, ExpressionKind, ForInit, Identifier, MethodDefinition, MethodKind,
+    Program, Property, Statement, StatementKind, UnaryOp, VariableDeclarator, VariableKind,
+};
+use crate::error::{LexErrorKind, ParseError, ParseErrorKind};
+use crate::operator::{Associativity, InfixKind, InfixOperator, OPERATORS};
+use crate::tokenizer::{Span, Token, TokenKind, Tokenizer};
+
+/// How deeply statements and expressions may nest before parsing gives
+/// up with `ParseErrorKind::NestingTooDeep` rather than overflowing the
+/// stack.
+const MAX_NESTING: usize = 128;
+
+pub struct Parser {
     string: String,
     tokenizer: Tokenizer,
-    lookahead: Option<Literal>,
+    lookahead: Option<Token>,
+    /// Span of the most recently consumed token.
+    previous_span: Span,
+    /// Number of function bodies enclosing the current position.
+    function_depth: usize,
+    /// Number of '{' consumed and not yet closed.
+    brace_depth: usize,
+    /// Number of statements and expressions being parsed inside one
+    /// another, see `MAX_NESTING`.
+    nesting: usize,
+    /// Infix operators, built-ins first; later entries take priority.
+    operators: Vec<InfixOperator>,
+    diagnostics: Vec<ParseError>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
-pub struct Literal {
-    pub literal_type: LiteralType,
-    pub value: Box<LiteralValue>,
+impl Default for Parser {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
-#[derive(Debug, Clone, PartialEq)]
-pub enum LiteralType {
-    Type(String),
-}
+impl Parser {
+    /// Initializes an empty parser.
+    pub fn new() -> Self {
+        Self::with_operators(vec![])
+    }
 
-#[derive(Debug, Clone, PartialEq)]
-pub enum LiteralValue {
-    Value(String),
-    NestedValue(Option<Literal>),
-    NestedValueList(Vec<Option<Literal>>),
-}
+    /// Initializes an empty parser that also accepts the given infix
+    /// operators.
+    ///
+    /// An operator whose symbol matches a built-in one replaces it, e.g.
+    /// to change its precedence.
+    ///
+    /// # Panics
+    ///
+    /// If an operator has an empty symbol, which would match everywhere.
+    pub fn with_operators(operators: Vec<InfixOperator>) -> Self {
+        assert!(
+            operators.iter().all(|operator| !operator.symbol.is_empty()),
+            "infix operator symbols must not be empty"
+        );
 
-impl Parser {
-    fn new() -> Self {
         Self {
             string: String::new(),
             tokenizer: Tokenizer::new(String::new()),
             lookahead: None,
+            previous_span: Span::default(),
+            function_depth: 0,
+            brace_depth: 0,
+            nesting: 0,
+            operators: OPERATORS.iter().copied().chain(operators).collect(),
+            diagnostics: vec![],
+        }
+    }
+
+    /// Parses a string into an AST, failing on the first error.
+    pub fn parse(&mut self, string: String) -> Result<Program, ParseError> {
+        let (program, mut diagnostics) = self.parse_with_diagnostics(string);
+        if diagnostics.is_empty() {
+            Ok(program)
+        } else {
+            Err(diagnostics.remove(0))
+        }
+    }
+
+    /// Parses a string into a best-effort AST, recovering from errors.
+    ///
+    /// Malformed statements are skipped up to the next `;` or `}` at their
+    /// own brace depth and replaced by `StatementKind::Error` nodes, and
+    /// tokens that fail to lex become `ExpressionKind::Error` nodes. Every
+    /// error encountered along the way is returned, in source order.
+    pub fn parse_with_diagnostics(&mut self, string: String) -> (Program, Vec<ParseError>) {
+        self.string = string.clone();
+        let symbols = self.operators[OPERATORS.len()..]
+            .iter()
+            .map(|operator| operator.symbol)
+            .filter(|symbol| OPERATORS.iter().all(|builtin| builtin.symbol != *symbol))
+            .collect();
+        self.tokenizer = Tokenizer::with_operators(string, symbols);
+        self.previous_span = Span::default();
+        self.function_depth = 0;
+        self.brace_depth = 0;
+        self.nesting = 0;
+        self.diagnostics = vec![];
+
+        // Prime the tokenizer to obtain the first token
+        // which is our lookahead for predictive parsing.
+        self.lookahead = self.next_token();
+
+        let program = self.program();
+        (program, std::mem::take(&mut self.diagnostics))
+    }
+
+    /// Main Entry Point
+    ///
+    /// Program
+    ///   : StatementList
+    ///   ;
+    fn program(&mut self) -> Program {
+        Program {
+            body: self.statement_list(None),
+            span: Span::new(0, self.string.len(), 1, 1),
+        }
+    }
+
+    /// Literal
+    ///   : NumericLiteral
+    ///   | StringLiteral
+    ///   | BooleanLiteral
+    ///   | NullLiteral
+    ///   ;
+    ///
+    /// `undefined` is not a literal but an ordinary identifier, as in
+    /// JavaScript.
+    fn literal(&mut self) -> Result<Expression, ParseError> {
+        match self.lookahead_kind() {
+            Some(TokenKind::Number) => self.numeric_literal(),
+            Some(TokenKind::String) => self.string_literal(),
+            Some(TokenKind::True) | Some(TokenKind::False) => self.boolean_literal(),
+            Some(TokenKind::Null) => self.null_literal(),
+            _ => Err(self.unexpected(&[
+                TokenKind::Number,
+                TokenKind::String,
+                TokenKind::True,
+                TokenKind::False,
+                TokenKind::Null,
+            ])),
+        }
+    }
+
+    /// Statement List
+    ///   : Statement
+    ///   | StatementList Statement -> Statement Statement Statement Statement
+    ///   ;
+    ///
+    /// Recovers from a malformed statement by recording the error and
+    /// skipping to the next statement boundary.
+    fn statement_list(&mut self, stop_lookahead: Option<TokenKind>) -> Vec<Statement> {
+        let mut statement_list = vec![];
+        while let Some(ref lookahead) = self.lookahead {
+            if Some(lookahead.kind) == stop_lookahead {
+                break;
+            }
+
+            let start = lookahead.span;
+            let depth = self.brace_depth;
+            match self.statement() {
+                Ok(statement) => statement_list.push(statement),
+                Err(err) => {
+                    if !self.is_lex_error_echo(&err) {
+                        self.diagnostics.push(err);
+                    }
+                    statement_list.push(self.synchronize(start, depth));
+                }
+            }
+        }
+
+        statement_list
+    }
+
+    /// Whether `err` only repeats a lexical error that is already among
+    /// the diagnostics: it is at a malformed token, or at the end of input
+    /// swallowed by an unterminated comment or string.
+    fn is_lex_error_echo(&self, err: &ParseError) -> bool {
+        match self.lookahead {
+            Some(ref token) => token.kind == TokenKind::Error && token.span == err.location,
+            None => matches!(
+                self.diagnostics.last(),
+                Some(ParseError {
+                    kind: ParseErrorKind::Lex(
+                        LexErrorKind::UnterminatedComment | LexErrorKind::UnterminatedString
+                    ),
+                    ..
+                })
+            ),
+        }
+    }
+
+    /// Skips tokens up to and including the next ';', or up to the next '}',
+    /// returning an error node covering everything skipped since `start`.
+    ///
+    /// Only delimiters at `depth`, the brace depth the statement started
+    /// at, count: a ';' or '}' inside an object literal or class body
+    /// that the statement opened is skipped over.
+    fn synchronize(&mut self, start: Span, depth: usize) -> Statement {
+        while let Some(kind) = self.lookahead_kind() {
+            let at_depth = self.brace_depth <= depth;
+            match kind {
+                TokenKind::Semicolon if at_depth => {
+                    self.advance();
+                    break;
+                }
+                TokenKind::CloseBrace if at_depth => break,
+                _ => {
+                    self.advance();
+                }
+            }
+        }
+
+        // Always make progress, e.g. past a stray '}' at the top level.
+        if self.previous_span.end <= start.start {
+            self.advance();
+        }
+
+        Statement {
+            kind: StatementKind::Error,
+            span: start.to(&self.previous_span),
+        }
+    }
+
+    /// Statement
+    ///   : ExpressionStatement
+    ///   | BlockStatement
+    ///   | EmptyStatement
+    ///   | VariableStatement
+    ///   | IfStatement
+    ///   | IterationStatement
+    ///   | FunctionDeclaration
+    ///   | ClassDeclaration
+    ///   | ReturnStatement
+    ///   ;
+    fn statement(&mut self) -> Result<Statement, ParseError> {
+        self.nested(|parser| match parser.lookahead_kind() {
+            Some(TokenKind::Semicolon) => parser.empty_statement(),
+            Some(TokenKind::Function) => parser.function_declaration(),
+            Some(TokenKind::Class) => parser.class_declaration(),
+            Some(TokenKind::Return) => parser.return_statement(),
+            // Never an object literal, see `primary_expression`.
+            Some(TokenKind::OpenBrace) => parser.block_statement(),
+            Some(TokenKind::If) => parser.if_statement(),
+            Some(TokenKind::While) => parser.while_statement(),
+            Some(TokenKind::Do) => parser.do_while_statement(),
+            Some(TokenKind::For) => parser.for_statement(),
+            Some(TokenKind::Let) | Some(TokenKind::Const) | Some(TokenKind::Var) => {
+                parser.variable_statement()
+            }
+            _ => parser.expression_statement(),
+        })
+    }
+
+    /// IfStatement
+    ///   : 'if' '(' Expression ')' Statement
+    ///   | 'if' '(' Expression ')' Statement 'else' Statement
+    ///   ;
+    ///
+    /// A dangling `else` binds to the nearest `if`.
+    fn if_statement(&mut self) -> Result<Statement, ParseError> {
+        let keyword = self.eat(TokenKind::If)?;
+        self.eat(TokenKind::OpenParen)?;
+        let test = self.expression()?;
+        self.eat(TokenKind::CloseParen)?;
+
+        let consequent = Box::new(self.statement()?);
+
+        let mut alternate = None;
+        if self.lookahead_kind() == Some(TokenKind::Else) {
+            self.eat(TokenKind::Else)?;
+            alternate = Some(Box::new(self.statement()?));
+        }
+
+        Ok(Statement {
+            kind: StatementKind::IfStatement {
+                test,
+                consequent,
+                alternate,
+            },
+            span: keyword.span.to(&self.previous_span),
+        })
+    }
+
+    /// FunctionDeclaration
+    ///   : 'function' Identifier FunctionRest
+    ///   ;
+    fn function_declaration(&mut self) -> Result<Statement, ParseError> {
+        let keyword = self.eat(TokenKind::Function)?;
+        let id = self.identifier()?;
+        let (params, body) = self.function_rest()?;
+
+        Ok(Statement {
+            span: keyword.span.to(&body.span),
+            kind: StatementKind::FunctionDeclaration { id, params, body },
+        })
+    }
+
+    /// FunctionRest
+    ///   : '(' OptFormalParameterList ')' BlockStatement
+    ///   ;
+    ///
+    /// Shared by function declarations, function expressions and class
+    /// methods.
+    fn function_rest(&mut self) -> Result<(Vec<Identifier>, Box<Statement>), ParseError> {
+        self.eat(TokenKind::OpenParen)?;
+        let mut params = vec![];
+        if self.lookahead_kind() != Some(TokenKind::CloseParen) {
+            params = self.formal_parameter_list()?;
+        }
+        self.eat(TokenKind::CloseParen)?;
+
+        self.function_depth += 1;
+        let body = self.block_statement();
+        self.function_depth -= 1;
+
+        Ok((params, Box::new(body?)))
+    }
+
+    /// ClassDeclaration
+    ///   : 'class' Identifier OptClassExtends '{' OptClassBody '}'
+    ///   ;
+    ///
+    /// ClassExtends
+    ///   : 'extends' LeftHandSideExpression
+    ///   ;
+    ///
+    /// ClassBody
+    ///   : MethodDefinition
+    ///   | ClassBody MethodDefinition
+    ///   ;
+    fn class_declaration(&mut self) -> Result<Statement, ParseError> {
+        let keyword = self.eat(TokenKind::Class)?;
+        let id = self.identifier()?;
+
+        let mut super_class = None;
+        if self.lookahead_kind() == Some(TokenKind::Extends) {
+            self.eat(TokenKind::Extends)?;
+            super_class = Some(self.left_hand_side_expression()?);
+        }
+
+        self.eat(TokenKind::OpenBrace)?;
+        let mut body = vec![];
+        while self.lookahead_kind() != Some(TokenKind::CloseBrace) {
+            if !self.at_property_name() {
+                return Err(self.unexpected(&[TokenKind::Identifier, TokenKind::CloseBrace]));
+            }
+            body.push(self.method_definition()?);
+        }
+        let close = self.eat(TokenKind::CloseBrace)?;
+
+        Ok(Statement {
+            kind: StatementKind::ClassDeclaration {
+                id,
+                super_class,
+                body,
+            },
+            span: keyword.span.to(&close.span),
+        })
+    }
+
+    /// MethodDefinition
+    ///   : Identifier FunctionRest
+    ///   ;
+    fn method_definition(&mut self) -> Result<MethodDefinition, ParseError> {
+        let key = self.property_name()?;
+        let (params, body) = self.function_rest()?;
+
+        Ok(MethodDefinition {
+            kind: if key.name == "constructor" {
+                MethodKind::Constructor
+            } else {
+                MethodKind::Method
+            },
+            span: key.span.to(&body.span),
+            key,
+            params,
+            body,
+        })
+    }
+
+    /// FormalParameterList
+    ///   : Identifier
+    ///   | FormalParameterList ',' Identifier
+    ///   ;
+    fn formal_parameter_list(&mut self) -> Result<Vec<Identifier>, ParseError> {
+        let mut params = vec![self.identifier()?];
+        while self.lookahead_kind() == Some(TokenKind::Comma) {
+            self.eat(TokenKind::Comma)?;
+            params.push(self.identifier()?);
+        }
+
+        Ok(params)
+    }
+
+    /// ReturnStatement
+    ///   : 'return' OptExpression ';'
+    ///   ;
+    fn return_statement(&mut self) -> Result<Statement, ParseError> {
+        if self.function_depth == 0 {
+            return Err(ParseError {
+                kind: ParseErrorKind::ReturnOutsideFunction,
+                expected: vec![],
+                location: self.lookahead.as_ref().unwrap().span,
+            });
+        }
+
+        let keyword = self.eat(TokenKind::Return)?;
+        let argument = self.optional_expression(TokenKind::Semicolon)?;
+        let semicolon = self.eat(TokenKind::Semicolon)?;
+
+        Ok(Statement {
+            kind: StatementKind::ReturnStatement { argument },
+            span: keyword.span.to(&semicolon.span),
+        })
+    }
+
+    /// WhileStatement
+    ///   : 'while' '(' Expression ')' Statement
+    ///   ;
+    fn while_statement(&mut self) -> Result<Statement, ParseError> {
+        let keyword = self.eat(TokenKind::While)?;
+        self.eat(TokenKind::OpenParen)?;
+        let test = self.expression()?;
+        self.eat(TokenKind::CloseParen)?;
+
+        let body = Box::new(self.statement()?);
+
+        Ok(Statement {
+            kind: StatementKind::WhileStatement { test, body },
+            span: keyword.span.to(&self.previous_span),
+        })
+    }
+
+    /// DoWhileStatement
+    ///   : 'do' Statement 'while' '(' Expression ')' ';'
+    ///   ;
+    fn do_while_statement(&mut self) -> Result<Statement, ParseError> {
+        let keyword = self.eat(TokenKind::Do)?;
+        let body = Box::new(self.statement()?);
+
+        self.eat(TokenKind::While)?;
+        self.eat(TokenKind::OpenParen)?;
+        let test = self.expression()?;
+        self.eat(TokenKind::CloseParen)?;
+        let semicolon = self.eat(TokenKind::Semicolon)?;
+
+        Ok(Statement {
+            kind: StatementKind::DoWhileStatement { body, test },
+            span: keyword.span.to(&semicolon.span),
+        })
+    }
+
+    /// ForStatement
+    ///   : 'for' '(' OptForStatementInit ';' OptExpression ';' OptExpression ')' Statement
+    ///   ;
+    ///
+    /// ForStatementInit
+    ///   : VariableStatementInit
+    ///   | Expression
+    ///   ;
+    fn for_statement(&mut self) -> Result<Statement, ParseError> {
+        let keyword = self.eat(TokenKind::For)?;
+        self.eat(TokenKind::OpenParen)?;
+
+        let init = match self.lookahead_kind() {
+            Some(TokenKind::Semicolon) => None,
+            Some(TokenKind::Let) | Some(TokenKind::Const) | Some(TokenKind::Var) => Some(
+                ForInit::VariableDeclaration(Box::new(self.variable_statement_init()?)),
+            ),
+            _ => Some(ForInit::Expression(self.expression()?)),
+        };
+        self.eat(TokenKind::Semicolon)?;
+
+        let test = self.optional_expression(TokenKind::Semicolon)?;
+        self.eat(TokenKind::Semicolon)?;
+
+        let update = self.optional_expression(TokenKind::CloseParen)?;
+        self.eat(TokenKind::CloseParen)?;
+
+        let body = Box::new(self.statement()?);
+
+        Ok(Statement {
+            kind: StatementKind::ForStatement {
+                init,
+                test,
+                update,
+                body,
+            },
+            span: keyword.span.to(&self.previous_span),
+        })
+    }
+
+    /// Parses an expression unless the lookahead is `terminator`.
+    fn optional_expression(
+        &mut self,
+        terminator: TokenKind,
+    ) -> Result<Option<Expression>, ParseError> {
+        if self.lookahead_kind() == Some(terminator) {
+            return Ok(None);
+        }
+        Ok(Some(self.expression()?))
+    }
+
+    /// VariableStatement
+    ///   : VariableStatementInit ';'
+    ///   ;
+    fn variable_statement(&mut self) -> Result<Statement, ParseError> {
+        let mut statement = self.variable_statement_init()?;
+        let semicolon = self.eat(TokenKind::Semicolon)?;
+
+        statement.span = statement.span.to(&semicolon.span);
+        Ok(statement)
+    }
+
+    /// VariableStatementInit
+    ///   : VariableKind VariableDeclarationList
+    ///   ;
+    ///
+    /// VariableKind
+    ///   : 'let'
+    ///   | 'const'
+    ///   | 'var'
+    ///   ;
+    ///
+    /// VariableDeclarationList
+    ///   : VariableDeclaration
+    ///   | VariableDeclarationList ',' VariableDeclaration
+    ///   ;
+    fn variable_statement_init(&mut self) -> Result<Statement, ParseError> {
+        let kind = match self.lookahead_kind() {
+            Some(TokenKind::Let) => VariableKind::Let,
+            Some(TokenKind::Const) => VariableKind::Const,
+            Some(TokenKind::Var) => VariableKind::Var,
+            _ => {
+                return Err(self.unexpected(&[TokenKind::Let, TokenKind::Const, TokenKind::Var]))
+            }
+        };
+        let keyword = self.advance().unwrap();
+
+        let mut declarations = vec![self.variable_declaration()?];
+        while self.lookahead_kind() == Some(TokenKind::Comma) {
+            self.eat(TokenKind::Comma)?;
+            declarations.push(self.variable_declaration()?);
+        }
+
+        Ok(Statement {
+            span: keyword.span.to(&self.previous_span),
+            kind: StatementKind::VariableDeclaration { kind, declarations },
+        })
+    }
+
+    /// VariableDeclaration
+    ///   : Identifier OptVariableInitializer
+    ///   ;
+    ///
+    /// VariableInitializer
+    ///   : SIMPLE_ASSIGN Expression
+    ///   ;
+    fn variable_declaration(&mut self) -> Result<VariableDeclarator, ParseError> {
+        let id = self.identifier()?;
+
+        let mut init = None;
+        if self.lookahead_kind() == Some(TokenKind::SimpleAssign) {
+            self.eat(TokenKind::SimpleAssign)?;
+            init = Some(self.expression()?);
+        }
+
+        Ok(VariableDeclarator {
+            span: id.span.to(&self.previous_span),
+            id,
+            init,
+        })
+    }
+
+    /// BlockStatement
+    ///  : '{' OptStatementList '}'
+    ///  ;
+    fn block_statement(&mut self) -> Result<Statement, ParseError> {
+        let open = self.eat(TokenKind::OpenBrace)?;
+
+        let mut body = vec![];
+        if self.lookahead_kind() != Some(TokenKind::CloseBrace) {
+            body = self.statement_list(Some(TokenKind::CloseBrace));
+        }
+
+        // The statement list only stops early at the end of input, so keep
+        // what was parsed of an unterminated block.
+        if let Err(err) = self.eat(TokenKind::CloseBrace) {
+            self.diagnostics.push(err);
         }
+
+        Ok(Statement {
+            kind: StatementKind::BlockStatement { body },
+            span: open.span.to(&self.previous_span),
+        })
+    }
+
+    /// EmptyStatement
+    ///   : ';'
+    ///   ;
+    fn empty_statement(&mut self) -> Result<Statement, ParseError> {
+        let semicolon = self.eat(TokenKind::Semicolon)?;
+        Ok(Statement {
+            kind: StatementKind::EmptyStatement,
+            span: semicolon.span,
+        })
+    }
+
+    /// ExpressionStatement
+    ///   : Expression ';'
+    ///   ;
+    fn expression_statement(&mut self) -> Result<Statement, ParseError> {
+        let expression = self.expression()?;
+        let semicolon = self.eat(TokenKind::Semicolon)?;
+
+        Ok(Statement {
+            span: expression.span.to(&semicolon.span),
+            kind: StatementKind::ExpressionStatement { expression },
+        })
+    }
+
+    /// Expression
+    ///   : AssignmentExpression
+    ///   ;
+    fn expression(&mut self) -> Result<Expression, ParseError> {
+        self.assignment_expression()
+    }
+
+    /// AssignmentExpression
+    ///   : ConditionalExpression
+    ///   | ArrowFunction
+    ///   | LeftHandSideExpression AssignmentOperator AssignmentExpression
+    ///   ;
+    ///
+    /// AssignmentOperator
+    ///   : SIMPLE_ASSIGN
+    ///   | COMPLEX_ASSIGN
+    ///   ;
+    fn assignment_expression(&mut self) -> Result<Expression, ParseError> {
+        self.nested(|parser| {
+            if parser.is_arrow_function_start() {
+                return parser.arrow_function();
+            }
+
+            let left = parser.conditional_expression()?;
+
+            if !matches!(
+                parser.lookahead_kind(),
+                Some(TokenKind::SimpleAssign) | Some(TokenKind::ComplexAssign)
+            ) {
+                return Ok(left);
+            }
+
+            if !left.is_assignment_target() {
+                return Err(ParseError {
+                    kind: ParseErrorKind::InvalidAssignmentTarget,
+                    expected: vec![],
+                    location: left.span,
+                });
+            }
+
+            let operator = parser.advance().unwrap();
+            // Right-recursive, so `x = y = 5` assigns `y = 5` to `x`.
+            let right = parser.assignment_expression()?;
+
+            Ok(Expression {
+                span: left.span.to(&right.span),
+                kind: ExpressionKind::AssignmentExpression {
+                    operator: AssignmentOp::from_operator(&operator.value).unwrap(),
+                    left: Box::new(left),
+                    right: Box::new(right),
+                },
+            })
+        })
+    }
+
+    /// Whether the lookahead starts an arrow function rather than a
+    /// parenthesised expression or identifier.
+    ///
+    /// Arrow parameters are plain identifiers, so scanning ahead stops at
+    /// the first token that could not be part of `(a, b) =>` or `a =>`.
+    fn is_arrow_function_start(&mut self) -> bool {
+        let lookahead_kind = self.lookahead_kind();
+        let checkpoint = self.tokenizer.checkpoint();
+        let mut next = || {
+            self.tokenizer
+                .get_next_token()
+                .ok()
+                .flatten()
+                .map(|token| token.kind)
+        };
+
+        let is_arrow = match lookahead_kind {
+            Some(TokenKind::Identifier) => next() == Some(TokenKind::Arrow),
+            Some(TokenKind::OpenParen) => loop {
+                match next() {
+                    Some(TokenKind::CloseParen) => break next() == Some(TokenKind::Arrow),
+                    Some(TokenKind::Identifier) => match next() {
+                        Some(TokenKind::Comma) => continue,
+                        Some(TokenKind::CloseParen) => break next() == Some(TokenKind::Arrow),
+                        _ => break false,
+                    },
+                    _ => break false,
+                }
+            },
+            _ => false,
+        };
+
+        self.tokenizer.rewind(checkpoint);
+        is_arrow
+    }
+
+    /// ArrowFunction
+    ///   : ArrowParameters '=>' ArrowBody
+    ///   ;
+    ///
+    /// ArrowParameters
+    ///   : Identifier
+    ///   | '(' OptFormalParameterList ')'
+    ///   ;
+    ///
+    /// ArrowBody
+    ///   : BlockStatement
+    ///   | AssignmentExpression
+    ///   ;
+    fn arrow_function(&mut self) -> Result<Expression, ParseError> {
+        let start = self.lookahead.as_ref().unwrap().span;
+        let params = if self.lookahead_kind() == Some(TokenKind::Identifier) {
+            vec![self.identifier()?]
+        } else {
+            self.eat(TokenKind::OpenParen)?;
+            let mut params = vec![];
+            if self.lookahead_kind() != Some(TokenKind::CloseParen) {
+                params = self.formal_parameter_list()?;
+            }
+            self.eat(TokenKind::CloseParen)?;
+            params
+        };
+        self.eat(TokenKind::Arrow)?;
+
+        let body = if self.lookahead_kind() == Some(TokenKind::OpenBrace) {
+            self.function_depth += 1;
+            let body = self.block_statement();
+            self.function_depth -= 1;
+            ArrowBody::Block(Box::new(body?))
+        } else {
+            ArrowBody::Expression(Box::new(self.assignment_expression()?))
+        };
+
+        Ok(Expression {
+            kind: ExpressionKind::ArrowFunctionExpression { params, body },
+            span: start.to(&self.previous_span),
+        })
+    }
+
+    /// ConditionalExpression
+    ///   : BinaryExpression
+    ///   | BinaryExpression '?' AssignmentExpression ':' AssignmentExpression
+    ///   ;
+    fn conditional_expression(&mut self) -> Result<Expression, ParseError> {
+        let test = self.binary_expression(0)?;
+
+        if self.lookahead_kind() != Some(TokenKind::QuestionMark) {
+            return Ok(test);
+        }
+
+        self.eat(TokenKind::QuestionMark)?;
+        let consequent = self.assignment_expression()?;
+        self.eat(TokenKind::Colon)?;
+        // Right-recursive, so `a ? b : c ? d : e` nests in the alternate.
+        let alternate = self.assignment_expression()?;
+
+        Ok(Expression {
+            span: test.span.to(&alternate.span),
+            kind: ExpressionKind::ConditionalExpression {
+                test: Box::new(test),
+                consequent: Box::new(consequent),
+                alternate: Box::new(alternate),
+            },
+        })
+    }
+
+    /// BinaryExpression
+    ///   : UnaryExpression
+    ///   | BinaryExpression INFIX_OPERATOR BinaryExpression
+    ///   ;
+    ///
+    /// Precedence climbing over the operator table: only operators
+    /// binding at least as tightly as `min_precedence` are consumed here,
+    /// looser ones are left to the caller. `&&` and `||` produce a
+    /// `LogicalExpression`, everything else a `BinaryExpression`.
+    ///
+    /// Precedences are widened to `u16` so that one above the highest
+    /// `u8` precedence still fits.
+    fn binary_expression(&mut self, min_precedence: u16) -> Result<Expression, ParseError> {
+        let mut left = self.unary_expression()?;
+
+        while let Some(operator) = self.infix_operator() {
+            let precedence = u16::from(operator.precedence);
+            if precedence < min_precedence {
+                break;
+            }
+            self.advance();
+
+            // A left-associative operator must not take an operand of the
+            // same precedence, so `a - b - c` groups as `(a - b) - c`.
+            let min_precedence = match operator.associativity {
+                Associativity::Left => precedence + 1,
+                Associativity::Right => precedence,
+            };
+            let right = self.nested(|parser| parser.binary_expression(min_precedence))?;
+
+            let span = left.span.to(&right.span);
+            let (left_node, right_node) = (Box::new(left), Box::new(right));
+            let kind = match operator.kind {
+                InfixKind::Logical(operator) => ExpressionKind::LogicalExpression {
+                    operator,
+                    left: left_node,
+                    right: right_node,
+                },
+                InfixKind::Binary(operator) => ExpressionKind::BinaryExpression {
+                    operator,
+                    left: left_node,
+                    right: right_node,
+                },
+            };
+            left = Expression { kind, span };
+        }
+
+        Ok(left)
+    }
+
+    /// Operator table entry for the lookahead, if it is an infix operator.
+    fn infix_operator(&self) -> Option<InfixOperator> {
+        let token = self.lookahead.as_ref()?;
+        if !matches!(
+            token.kind,
+            TokenKind::LogicalOr
+                | TokenKind::LogicalAnd
+                | TokenKind::EqualityOperator
+                | TokenKind::RelationalOperator
+                | TokenKind::AdditiveOperator
+                | TokenKind::MultiplicativeOperator
+                | TokenKind::CustomOperator
+        ) {
+            return None;
+        }
+
+        self.operators
+            .iter()
+            .rev()
+            .find(|operator| operator.symbol == token.value)
+            .copied()
+    }
+
+    /// UnaryExpression
+    ///   : LeftHandSideExpression
+    ///   | ADDITIVE_OPERATOR UnaryExpression
+    ///   | LOGICAL_NOT UnaryExpression
+    ///   | 'typeof' UnaryExpression
+    ///   | 'void' UnaryExpression
+    ///   | 'delete' UnaryExpression
+    ///   ;
+    fn unary_expression(&mut self) -> Result<Expression, ParseError> {
+        self.nested(|parser| {
+            if !matches!(
+                parser.lookahead_kind(),
+                Some(TokenKind::AdditiveOperator)
+                    | Some(TokenKind::LogicalNot)
+                    | Some(TokenKind::Typeof)
+                    | Some(TokenKind::Void)
+                    | Some(TokenKind::Delete)
+            ) {
+                return parser.left_hand_side_expression();
+            }
+
+            let operator = parser.advance().unwrap();
+            let argument = parser.unary_expression()?;
+
+            Ok(Expression {
+                span: operator.span.to(&argument.span),
+                kind: ExpressionKind::UnaryExpression {
+                    operator: UnaryOp::from_operator(&operator.value).unwrap(),
+                    argument: Box::new(argument),
+                },
+            })
+        })
+    }
+
+    /// LeftHandSideExpression
+    ///   : CallMemberExpression
+    ///   ;
+    fn left_hand_side_expression(&mut self) -> Result<Expression, ParseError> {
+        self.call_member_expression()
+    }
+
+    /// CallMemberExpression
+    ///   : MemberExpression
+    ///   | CallMemberExpression '.' Identifier
+    ///   | CallMemberExpression '[' Expression ']'
+    ///   | CallMemberExpression Arguments
+    ///   ;
+    fn call_member_expression(&mut self) -> Result<Expression, ParseError> {
+        let expression = self.member_expression()?;
+        self.member_tail(expression, true)
+    }
+
+    /// MemberExpression
+    ///   : PrimaryExpression
+    ///   | NewExpression
+    ///   | MemberExpression '.' Identifier
+    ///   | MemberExpression '[' Expression ']'
+    ///   ;
+    ///
+    /// NewExpression
+    ///   : 'new' MemberExpression OptArguments
+    ///   ;
+    ///
+    /// The callee of `new` stops before the first `(`, so `new a.B(1).c`
+    /// is `(new a.B(1)).c`.
+    fn member_expression(&mut self) -> Result<Expression, ParseError> {
+        if self.lookahead_kind() != Some(TokenKind::New) {
+            return self.primary_expression();
+        }
+
+        let keyword = self.eat(TokenKind::New)?;
+        let callee = self.nested(Self::member_expression)?;
+        let callee = self.member_tail(callee, false)?;
+        let mut arguments = vec![];
+        if self.lookahead_kind() == Some(TokenKind::OpenParen) {
+            arguments = self.arguments()?;
+        }
+
+        Ok(Expression {
+            kind: ExpressionKind::NewExpression {
+                callee: Box::new(callee),
+                arguments,
+            },
+            span: keyword.span.to(&self.previous_span),
+        })
+    }
+
+    /// Applies any following `.property`, `[property]` and, if `calls` is
+    /// set, `(arguments)` suffixes to `expression`.
+    fn member_tail(
+        &mut self,
+        mut expression: Expression,
+        calls: bool,
+    ) -> Result<Expression, ParseError> {
+        loop {
+            let start = expression.span;
+            let kind = match self.lookahead_kind() {
+                Some(TokenKind::Dot) => {
+                    self.eat(TokenKind::Dot)?;
+                    let property = self.property_name()?;
+                    ExpressionKind::MemberExpression {
+                        object: Box::new(expression),
+                        property: Box::new(Expression {
+                            kind: ExpressionKind::Identifier {
+                                name: property.name,
+                            },
+                            span: property.span,
+                        }),
+                        computed: false,
+                    }
+                }
+                Some(TokenKind::OpenBracket) => {
+                    self.eat(TokenKind::OpenBracket)?;
+                    let property = self.expression()?;
+                    self.eat(TokenKind::CloseBracket)?;
+                    ExpressionKind::MemberExpression {
+                        object: Box::new(expression),
+                        property: Box::new(property),
+                        computed: true,
+                    }
+                }
+                Some(TokenKind::OpenParen) if calls => ExpressionKind::CallExpression {
+                    arguments: self.arguments()?,
+                    callee: Box::new(expression),
+                },
+                _ => return Ok(expression),
+            };
+
+            expression = Expression {
+                kind,
+                span: start.to(&self.previous_span),
+            };
+        }
+    }
+
+    /// Arguments
+    ///   : '(' OptArgumentList ')'
+    ///   ;
+    ///
+    /// ArgumentList
+    ///   : AssignmentExpression
+    ///   | ArgumentList ',' AssignmentExpression
+    ///   ;
+    fn arguments(&mut self) -> Result<Vec<Expression>, ParseError> {
+        self.eat(TokenKind::OpenParen)?;
+
+        let mut arguments = vec![];
+        if self.lookahead_kind() != Some(TokenKind::CloseParen) {
+            arguments.push(self.assignment_expression()?);
+            while self.lookahead_kind() == Some(TokenKind::Comma) {
+                self.eat(TokenKind::Comma)?;
+                arguments.push(self.assignment_expression()?);
+            }
+        }
+
+        self.eat(TokenKind::CloseParen)?;
+        Ok(arguments)
+    }
+
+    /// Primary Expression
+    ///   : Literal
+    ///   | ArrayLiteral
+    ///   | ObjectLiteral
+    ///   | ParenthesisedExpression
+    ///   | Identifier
+    ///   | FunctionExpression
+    ///   | 'this'
+    ///   | 'super'
+    ///   ;
+    ///
+    /// `statement` claims a leading `{` for a block, so an object literal
+    /// at the start of a statement has to be parenthesised: `({ a: 1 });`.
+    fn primary_expression(&mut self) -> Result<Expression, ParseError> {
+        match self.lookahead_kind() {
+            Some(TokenKind::OpenParen) => self.parenthesised_expression(),
+            Some(TokenKind::OpenBracket) => self.array_literal(),
+            Some(TokenKind::OpenBrace) => self.object_literal(),
+            Some(TokenKind::Function) => self.function_expression(),
+            Some(TokenKind::This) => {
+                let keyword = self.eat(TokenKind::This)?;
+                Ok(Expression {
+                    kind: ExpressionKind::ThisExpression,
+                    span: keyword.span,
+                })
+            }
+            Some(TokenKind::Super) => {
+                let keyword = self.eat(TokenKind::Super)?;
+                // `super` on its own is not a value, only `super(...)`,
+                // `super.x` and `super[x]` are.
+                if !matches!(
+                    self.lookahead_kind(),
+                    Some(TokenKind::OpenParen) | Some(TokenKind::Dot) | Some(TokenKind::OpenBracket)
+                ) {
+                    return Err(self.unexpected(&[
+                        TokenKind::OpenParen,
+                        TokenKind::Dot,
+                        TokenKind::OpenBracket,
+                    ]));
+                }
+                Ok(Expression {
+                    kind: ExpressionKind::Super,
+                    span: keyword.span,
+                })
+            }
+            Some(TokenKind::Number)
+            | Some(TokenKind::String)
+            | Some(TokenKind::True)
+            | Some(TokenKind::False)
+            | Some(TokenKind::Null) => self.literal(),
+            Some(TokenKind::Identifier) => {
+                let identifier = self.identifier()?;
+                Ok(Expression {
+                    kind: ExpressionKind::Identifier {
+                        name: identifier.name,
+                    },
+                    span: identifier.span,
+                })
+            }
+            // Already reported by `next_token`.
+            Some(TokenKind::Error) => {
+                let token = self.advance().unwrap();
+                Ok(Expression {
+                    kind: ExpressionKind::Error,
+                    span: token.span,
+                })
+            }
+            _ => Err(self.expected_expression()),
+        }
+    }
+
+    /// FunctionExpression
+    ///   : 'function' OptIdentifier FunctionRest
+    ///   ;
+    fn function_expression(&mut self) -> Result<Expression, ParseError> {
+        let keyword = self.eat(TokenKind::Function)?;
+        let mut id = None;
+        if self.lookahead_kind() == Some(TokenKind::Identifier) {
+            id = Some(self.identifier()?);
+        }
+        let (params, body) = self.function_rest()?;
+
+        Ok(Expression {
+            span: keyword.span.to(&body.span),
+            kind: ExpressionKind::FunctionExpression { id, params, body },
+        })
+    }
+
+    /// ArrayLiteral
+    ///   : '[' OptElementList ']'
+    ///   ;
+    ///
+    /// ElementList
+    ///   : OptAssignmentExpression
+    ///   | ElementList ',' OptAssignmentExpression
+    ///   ;
+    ///
+    /// An empty element is a hole, except after a trailing comma:
+    /// `[1, , 2,]` has three elements.
+    fn array_literal(&mut self) -> Result<Expression, ParseError> {
+        let open = self.eat(TokenKind::OpenBracket)?;
+
+        let mut elements = vec![];
+        while self.lookahead_kind() != Some(TokenKind::CloseBracket) {
+            if self.lookahead_kind() == Some(TokenKind::Comma) {
+                self.eat(TokenKind::Comma)?;
+                elements.push(None);
+                continue;
+            }
+
+            elements.push(Some(self.assignment_expression()?));
+            match self.lookahead_kind() {
+                Some(TokenKind::Comma) => {
+                    self.eat(TokenKind::Comma)?;
+                }
+                Some(TokenKind::CloseBracket) => {}
+                _ => return Err(self.unexpected(&[TokenKind::Comma, TokenKind::CloseBracket])),
+            }
+        }
+
+        let close = self.eat(TokenKind::CloseBracket)?;
+        Ok(Expression {
+            kind: ExpressionKind::ArrayExpression { elements },
+            span: open.span.to(&close.span),
+        })
+    }
+
+    /// ObjectLiteral
+    ///   : '{' OptPropertyList '}'
+    ///   | '{' PropertyList ',' '}'
+    ///   ;
+    ///
+    /// PropertyList
+    ///   : Property
+    ///   | PropertyList ',' Property
+    ///   ;
+    fn object_literal(&mut self) -> Result<Expression, ParseError> {
+        let open = self.eat(TokenKind::OpenBrace)?;
+
+        let mut properties = vec![];
+        while self.lookahead_kind() != Some(TokenKind::CloseBrace) {
+            properties.push(self.property()?);
+            match self.lookahead_kind() {
+                Some(TokenKind::Comma) => {
+                    self.eat(TokenKind::Comma)?;
+                }
+                Some(TokenKind::CloseBrace) => {}
+                _ => return Err(self.unexpected(&[TokenKind::Comma, TokenKind::CloseBrace])),
+            }
+        }
+
+        let close = self.eat(TokenKind::CloseBrace)?;
+        Ok(Expression {
+            kind: ExpressionKind::ObjectExpression { properties },
+            span: open.span.to(&close.span),
+        })
+    }
+
+    /// Property
+    ///   : PropertyName ':' AssignmentExpression
+    ///   ;
+    ///
+    /// PropertyName
+    ///   : IdentifierName
+    ///   | Literal
+    ///   | '[' AssignmentExpression ']'
+    ///   ;
+    fn property(&mut self) -> Result<Property, ParseError> {
+        let start = self.lookahead.as_ref().map(|token| token.span);
+        let (key, computed) = match self.lookahead_kind() {
+            _ if self.at_property_name() => {
+                let identifier = self.property_name()?;
+                let key = Expression {
+                    kind: ExpressionKind::Identifier {
+                        name: identifier.name,
+                    },
+                    span: identifier.span,
+                };
+                (key, false)
+            }
+            Some(TokenKind::Number) | Some(TokenKind::String) => (self.literal()?, false),
+            Some(TokenKind::OpenBracket) => {
+                self.eat(TokenKind::OpenBracket)?;
+                let key = self.assignment_expression()?;
+                self.eat(TokenKind::CloseBracket)?;
+                (key, true)
+            }
+            _ => {
+                return Err(self.unexpected(&[
+                    TokenKind::Identifier,
+                    TokenKind::String,
+                    TokenKind::Number,
+                    TokenKind::OpenBracket,
+                ]))
+            }
+        };
+
+        self.eat(TokenKind::Colon)?;
+        let value = self.assignment_expression()?;
+
+        Ok(Property {
+            span: start.unwrap().to(&value.span),
+            key,
+            value,
+            computed,
+        })
+    }
+
+    /// Identifier
+    ///   : IDENTIFIER
+    ///   ;
+    fn identifier(&mut self) -> Result<Identifier, ParseError> {
+        let token = self.eat(TokenKind::Identifier)?;
+        Ok(Identifier {
+            name: token.value,
+            span: token.span,
+        })
+    }
+
+    /// IdentifierName
+    ///   : IDENTIFIER
+    ///   | Keyword
+    ///   | CUSTOM_OPERATOR
+    ///   ;
+    ///
+    /// Property and method names may be any word, reserved or not, as in
+    /// `map.delete(key)`, including a custom operator such as `in`.
+    fn property_name(&mut self) -> Result<Identifier, ParseError> {
+        if !self.at_property_name() {
+            return Err(self.unexpected(&[TokenKind::Identifier]));
+        }
+
+        let token = self.advance().unwrap();
+        Ok(Identifier {
+            name: token.value,
+            span: token.span,
+        })
+    }
+
+    /// Whether the lookahead can be used as a property name.
+    fn at_property_name(&self) -> bool {
+        self.lookahead.as_ref().is_some_and(|token| match token.kind {
+            TokenKind::Identifier => true,
+            TokenKind::CustomOperator => token
+                .value
+                .chars()
+                .all(|character| character.is_alphanumeric() || "_$".contains(character)),
+            kind => kind.is_keyword(),
+        })
+    }
+
+    /// Parenthesised Expression
+    ///   : '(' Expression ')'
+    ///   ;
+    fn parenthesised_expression(&mut self) -> Result<Expression, ParseError> {
+        let open = self.eat(TokenKind::OpenParen)?;
+        let mut expression = self.expression()?;
+        let close = self.eat(TokenKind::CloseParen)?;
+
+        expression.span = open.span.to(&close.span);
+        Ok(expression)
+    }
+
+    /// Numeric Literal
+    ///   : NUMBER
+    ///   ;
+    fn numeric_literal(&mut self) -> Result<Expression, ParseError> {
+        let token = self.eat(TokenKind::Number)?;
+        Ok(Expression {
+            kind: ExpressionKind::NumericLiteral {
+                value: numeric_value(&token.value),
+                raw: token.value,
+            },
+            span: token.span,
+        })
+    }
+
+    /// String Literal
+    ///   : STRING
+    ///   ;
+    fn string_literal(&mut self) -> Result<Expression, ParseError> {
+        let token = self.eat(TokenKind::String)?;
+        Ok(Expression {
+            kind: ExpressionKind::StringLiteral {
+                value: token.value[1..token.value.len() - 1].to_string(),
+            },
+            span: token.span,
+        })
+    }
+
+    /// Boolean Literal
+    ///   : 'true'
+    ///   | 'false'
+    ///   ;
+    fn boolean_literal(&mut self) -> Result<Expression, ParseError> {
+        let token = match self.lookahead_kind() {
+            Some(TokenKind::True) => self.eat(TokenKind::True)?,
+            _ => self.eat(TokenKind::False)?,
+        };
+        Ok(Expression {
+            kind: ExpressionKind::BooleanLiteral {
+                value: token.kind == TokenKind::True,
+            },
+            span: token.span,
+        })
+    }
+
+    /// Null Literal
+    ///   : 'null'
+    ///   ;
+    fn null_literal(&mut self) -> Result<Expression, ParseError> {
+        let token = self.eat(TokenKind::Null)?;
+        Ok(Expression {
+            kind: ExpressionKind::NullLiteral,
+            span: token.span,
+        })
+    }
+
+    fn eat(&mut self, token_kind: TokenKind) -> Result<Token, ParseError> {
+        if self.lookahead_kind() != Some(token_kind) {
+            return Err(self.unexpected(&[token_kind]));
+        }
+
+        Ok(self.advance().unwrap())
+    }
+
+    /// Runs `parse` one nesting level deeper, failing instead once
+    /// `MAX_NESTING` is reached.
+    fn nested<T>(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        if self.nesting == MAX_NESTING {
+            return Err(ParseError {
+                kind: ParseErrorKind::NestingTooDeep,
+                ..self.unexpected(&[])
+            });
+        }
+
+        self.nesting += 1;
+        let result = parse(self);
+        self.nesting -= 1;
+        result
+    }
+
+    /// Consumes the lookahead token and returns it.
+    fn advance(&mut self) -> Option<Token> {
+        let next = self.next_token();
+        let token = std::mem::replace(&mut self.lookahead, next)?;
+        match token.kind {
+            TokenKind::OpenBrace => self.brace_depth += 1,
+            TokenKind::CloseBrace => self.brace_depth = self.brace_depth.saturating_sub(1),
+            _ => {}
+        }
+        self.previous_span = token.span;
+        Some(token)
+    }
+
+    /// Pulls the next token, recording a lexical error as a diagnostic
+    /// and standing a `TokenKind::Error` token in for the malformed input.
+    fn next_token(&mut self) -> Option<Token> {
+        match self.tokenizer.get_next_token() {
+            Ok(token) => token,
+            Err(err) => {
+                let span = err.location;
+                self.diagnostics.push(err.into());
+                Some(Token {
+                    kind: TokenKind::Error,
+                    value: self.string[span.start..span.end].to_string(),
+                    span,
+                })
+            }
+        }
+    }
+
+    /// Kind of the current lookahead token, if any.
+    fn lookahead_kind(&self) -> Option<TokenKind> {
+        self.lookahead.as_ref().map(|token| token.kind)
+    }
+
+    /// Builds an error for a lookahead that cannot start an expression.
+    fn expected_expression(&self) -> ParseError {
+        ParseError {
+            kind: ParseErrorKind::ExpectedExpression(
+                self.lookahead.as_ref().map(|token| token.value.clone()),
+            ),
+            ..self.unexpected(&[])
+        }
+    }
+
+    /// Builds an error for a lookahead that none of the `expected` tokens matched.
+    fn unexpected(&self, expected: &[TokenKind]) -> ParseError {
+        match self.lookahead {
+            Some(ref token) => ParseError {
+                kind: ParseErrorKind::UnexpectedToken(token.value.clone()),
+                expected: expected.to_vec(),
+                location: token.span,
+            },
+            None => ParseError {
+                kind: ParseErrorKind::UnexpectedEof,
+                expected: expected.to_vec(),
+                location: self.tokenizer.current_span(),
+            },
+        }
+    }
+}
+
+/// Value of a `NUMBER` token, which the tokenizer has already validated.
+fn numeric_value(raw: &str) -> f64 {
+    let digits = raw.replace('_', "");
+    let radix = match digits.get(..2) {
+        Some("0x") | Some("0X") => 16,
+        Some("0o") | Some("0O") => 8,
+        Some("0b") | Some("0B") => 2,
+        _ => return digits.parse().unwrap(),
+    };
+
+    // Folded as floats, like JavaScript, so long literals lose precision
+    // instead of overflowing.
+    digits[2..].chars().fold(0.0, |value, digit| {
+        value * radix as f64 + digit.to_digit(radix).unwrap() as f64
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::*;
+    use crate::error::{LexErrorKind, ParseError, ParseErrorKind};
+    use crate::parser::*;
+    use crate::tokenizer::{Span, TokenKind};
+
+    /// Numeric literal `raw` of `value` at byte `start` of a single-line
+    /// program.
+    fn number(raw: &str, value: f64, start: usize) -> Expression {
+        Expression {
+            kind: ExpressionKind::NumericLiteral {
+                raw: String::from(raw),
+                value,
+            },
+            span: Span::new(start, start + raw.len(), 1, start + 1),
+        }
+    }
+
+    /// Binding `name` at byte `start` of a single-line program.
+    fn identifier(name: &str, start: usize) -> Identifier {
+        Identifier {
+            name: String::from(name),
+            span: Span::new(start, start + name.len(), 1, start + 1),
+        }
+    }
+
+    /// Reference to `name` at byte `start` of a single-line program.
+    fn reference(name: &str, start: usize) -> Expression {
+        Expression {
+            kind: ExpressionKind::Identifier {
+                name: String::from(name),
+            },
+            span: Span::new(start, start + name.len(), 1, start + 1),
+        }
+    }
+
+    #[test]
+    fn test_statement_lists() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from(
+            r#"
+            // Program
+            /*
+                Multiline comments...
+            */
+            "hello";
+            42;
+            "#,
+        );
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![
+                    Statement {
+                        kind: StatementKind::ExpressionStatement {
+                            expression: Expression {
+                                kind: ExpressionKind::StringLiteral {
+                                    value: String::from("hello"),
+                                },
+                                span: Span::new(104, 111, 6, 13),
+                            },
+                        },
+                        span: Span::new(104, 112, 6, 13),
+                    },
+                    Statement {
+                        kind: StatementKind::ExpressionStatement {
+                            expression: Expression {
+                                kind: ExpressionKind::NumericLiteral {
+                                    raw: String::from("42"),
+                                    value: 42.0,
+                                },
+                                span: Span::new(125, 127, 7, 13),
+                            },
+                        },
+                        span: Span::new(125, 128, 7, 13),
+                    },
+                ],
+                span: Span::new(0, 141, 1, 1),
+            })
+        );
+        let _ = dbg!(ast);
+    }
+
+    #[test]
+    fn test_string_literals() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from(
+            r#"
+            "hello";
+            "#,
+        );
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ExpressionStatement {
+                        expression: Expression {
+                            kind: ExpressionKind::StringLiteral {
+                                value: String::from("hello"),
+                            },
+                            span: Span::new(13, 20, 2, 13),
+                        },
+                    },
+                    span: Span::new(13, 21, 2, 13),
+                }],
+                span: Span::new(0, 34, 1, 1),
+            })
+        );
+        let _ = dbg!(ast);
+    }
+
+    #[test]
+    fn test_numeric_literals() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from(
+            r#"
+            42;
+            "#,
+        );
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ExpressionStatement {
+                        expression: Expression {
+                            kind: ExpressionKind::NumericLiteral {
+                                raw: String::from("42"),
+                                value: 42.0,
+                            },
+                            span: Span::new(13, 15, 2, 13),
+                        },
+                    },
+                    span: Span::new(13, 16, 2, 13),
+                }],
+                span: Span::new(0, 29, 1, 1),
+            })
+        );
+        let _ = dbg!(ast);
+    }
+
+    #[test]
+    fn test_boolean_and_null_literals() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("[true, false, null, trueValue, undefined];");
+
+        let ast = parser.parse(program);
+
+        let element = |kind: ExpressionKind, start: usize, end: usize| {
+            Some(Expression {
+                kind,
+                span: Span::new(start, end, 1, start + 1),
+            })
+        };
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ExpressionStatement {
+                        expression: Expression {
+                            kind: ExpressionKind::ArrayExpression {
+                                elements: vec![
+                                    element(ExpressionKind::BooleanLiteral { value: true }, 1, 5),
+                                    element(ExpressionKind::BooleanLiteral { value: false }, 7, 12),
+                                    element(ExpressionKind::NullLiteral, 14, 18),
+                                    element(
+                                        ExpressionKind::Identifier {
+                                            name: String::from("trueValue"),
+                                        },
+                                        20,
+                                        29,
+                                    ),
+                                    element(
+                                        ExpressionKind::Identifier {
+                                            name: String::from("undefined"),
+                                        },
+                                        31,
+                                        40,
+                                    ),
+                                ],
+                            },
+                            span: Span::new(0, 41, 1, 1),
+                        },
+                    },
+                    span: Span::new(0, 42, 1, 1),
+                }],
+                span: Span::new(0, 42, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_empty_statements() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from(";; { ; } while (x) ;");
+
+        let ast = parser.parse(program);
+
+        let empty = |start: usize| Statement {
+            kind: StatementKind::EmptyStatement,
+            span: Span::new(start, start + 1, 1, start + 1),
+        };
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![
+                    empty(0),
+                    empty(1),
+                    Statement {
+                        kind: StatementKind::BlockStatement {
+                            body: vec![empty(5)],
+                        },
+                        span: Span::new(3, 8, 1, 4),
+                    },
+                    Statement {
+                        kind: StatementKind::WhileStatement {
+                            test: Expression {
+                                kind: ExpressionKind::Identifier {
+                                    name: String::from("x"),
+                                },
+                                span: Span::new(16, 17, 1, 17),
+                            },
+                            body: Box::new(empty(19)),
+                        },
+                        span: Span::new(9, 20, 1, 10),
+                    },
+                ],
+                span: Span::new(0, 20, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_numeric_literal_values() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("[2.75, 1e9, 0xFF, 0b1010, 1_000_000];");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ExpressionStatement {
+                        expression: Expression {
+                            kind: ExpressionKind::ArrayExpression {
+                                elements: vec![
+                                    Some(number("2.75", 2.75, 1)),
+                                    Some(number("1e9", 1_000_000_000.0, 7)),
+                                    Some(number("0xFF", 255.0, 12)),
+                                    Some(number("0b1010", 10.0, 18)),
+                                    Some(number("1_000_000", 1_000_000.0, 26)),
+                                ],
+                            },
+                            span: Span::new(0, 36, 1, 1),
+                        },
+                    },
+                    span: Span::new(0, 37, 1, 1),
+                }],
+                span: Span::new(0, 37, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from(
+            r#"
+            {
+              42;
+              "hello";
+            }
+            "#,
+        );
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::BlockStatement {
+                        body: vec![
+                            Statement {
+                                kind: StatementKind::ExpressionStatement {
+                                    expression: Expression {
+                                        kind: ExpressionKind::NumericLiteral {
+                                            raw: String::from("42"),
+                                            value: 42.0,
+                                        },
+                                        span: Span::new(29, 31, 3, 15),
+                                    },
+                                },
+                                span: Span::new(29, 32, 3, 15),
+                            },
+                            Statement {
+                                kind: StatementKind::ExpressionStatement {
+                                    expression: Expression {
+                                        kind: ExpressionKind::StringLiteral {
+                                            value: String::from("hello"),
+                                        },
+                                        span: Span::new(47, 54, 4, 15),
+                                    },
+                                },
+                                span: Span::new(47, 55, 4, 15),
+                            },
+                        ],
+                    },
+                    span: Span::new(13, 69, 2, 13),
+                }],
+                span: Span::new(0, 82, 1, 1),
+            })
+        );
+        let _ = dbg!(ast);
+    }
+
+    #[test]
+    fn test_empty_block() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from(
+            r#"
+            {
+              // ...
+            }
+            "#,
+        );
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::BlockStatement { body: vec![] },
+                    span: Span::new(13, 49, 2, 13),
+                }],
+                span: Span::new(0, 62, 1, 1),
+            })
+        );
+        let _ = dbg!(ast);
+    }
+
+    #[test]
+    fn test_nested_blocks() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from(
+            r#"
+            {
+              42;
+              {
+                "hello";
+              }
+            }
+            "#,
+        );
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::BlockStatement {
+                        body: vec![
+                            Statement {
+                                kind: StatementKind::ExpressionStatement {
+                                    expression: Expression {
+                                        kind: ExpressionKind::NumericLiteral {
+                                            raw: String::from("42"),
+                                            value: 42.0,
+                                        },
+                                        span: Span::new(29, 31, 3, 15),
+                                    },
+                                },
+                                span: Span::new(29, 32, 3, 15),
+                            },
+                            Statement {
+                                kind: StatementKind::BlockStatement {
+                                    body: vec![Statement {
+                                        kind: StatementKind::ExpressionStatement {
+                                            expression: Expression {
+                                                kind: ExpressionKind::StringLiteral {
+                                                    value: String::from("hello"),
+                                                },
+                                                span: Span::new(65, 72, 5, 17),
+                                            },
+                                        },
+                                        span: Span::new(65, 73, 5, 17),
+                                    }],
+                                },
+                                span: Span::new(47, 89, 4, 15),
+                            },
+                        ],
+                    },
+                    span: Span::new(13, 103, 2, 13),
+                }],
+                span: Span::new(0, 116, 1, 1),
+            })
+        );
+        let _ = dbg!(ast);
+    }
+
+    #[test]
+    fn test_math() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from(
+            r#"
+            2 + 2 * 2;
+            "#,
+        );
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ExpressionStatement {
+                        expression: Expression {
+                            kind: ExpressionKind::BinaryExpression {
+                                operator: BinaryOp::Add,
+                                left: Box::new(Expression {
+                                    kind: ExpressionKind::NumericLiteral {
+                                        raw: String::from("2"),
+                                        value: 2.0,
+                                    },
+                                    span: Span::new(13, 14, 2, 13),
+                                }),
+                                right: Box::new(Expression {
+                                    kind: ExpressionKind::BinaryExpression {
+                                        operator: BinaryOp::Multiply,
+                                        left: Box::new(Expression {
+                                            kind: ExpressionKind::NumericLiteral {
+                                                raw: String::from("2"),
+                                                value: 2.0,
+                                            },
+                                            span: Span::new(17, 18, 2, 17),
+                                        }),
+                                        right: Box::new(Expression {
+                                            kind: ExpressionKind::NumericLiteral {
+                                                raw: String::from("2"),
+                                                value: 2.0,
+                                            },
+                                            span: Span::new(21, 22, 2, 21),
+                                        }),
+                                    },
+                                    span: Span::new(17, 22, 2, 17),
+                                }),
+                            },
+                            span: Span::new(13, 22, 2, 13),
+                        },
+                    },
+                    span: Span::new(13, 23, 2, 13),
+                }],
+                span: Span::new(0, 36, 1, 1),
+            })
+        );
+        let _ = dbg!(ast);
+    }
+
+    #[test]
+    fn test_op_precedence() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from(
+            r#"
+            (2 + 2) * 2;
+            "#,
+        );
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ExpressionStatement {
+                        expression: Expression {
+                            kind: ExpressionKind::BinaryExpression {
+                                operator: BinaryOp::Multiply,
+                                left: Box::new(Expression {
+                                    kind: ExpressionKind::BinaryExpression {
+                                        operator: BinaryOp::Add,
+                                        left: Box::new(Expression {
+                                            kind: ExpressionKind::NumericLiteral {
+                                                raw: String::from("2"),
+                                                value: 2.0,
+                                            },
+                                            span: Span::new(14, 15, 2, 14),
+                                        }),
+                                        right: Box::new(Expression {
+                                            kind: ExpressionKind::NumericLiteral {
+                                                raw: String::from("2"),
+                                                value: 2.0,
+                                            },
+                                            span: Span::new(18, 19, 2, 18),
+                                        }),
+                                    },
+                                    span: Span::new(13, 20, 2, 13),
+                                }),
+                                right: Box::new(Expression {
+                                    kind: ExpressionKind::NumericLiteral {
+                                        raw: String::from("2"),
+                                        value: 2.0,
+                                    },
+                                    span: Span::new(23, 24, 2, 23),
+                                }),
+                            },
+                            span: Span::new(13, 24, 2, 13),
+                        },
+                    },
+                    span: Span::new(13, 25, 2, 13),
+                }],
+                span: Span::new(0, 38, 1, 1),
+            })
+        );
+        let _ = dbg!(ast);
+    }
+
+    #[test]
+    fn test_variable_declaration() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("let x = 42;");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::VariableDeclaration {
+                        kind: VariableKind::Let,
+                        declarations: vec![VariableDeclarator {
+                            id: Identifier {
+                                name: String::from("x"),
+                                span: Span::new(4, 5, 1, 5),
+                            },
+                            init: Some(Expression {
+                                kind: ExpressionKind::NumericLiteral {
+                                    raw: String::from("42"),
+                                    value: 42.0,
+                                },
+                                span: Span::new(8, 10, 1, 9),
+                            }),
+                            span: Span::new(4, 10, 1, 5),
+                        }],
+                    },
+                    span: Span::new(0, 11, 1, 1),
+                }],
+                span: Span::new(0, 11, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_multiple_variable_declarators() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("let a, b = 2;");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::VariableDeclaration {
+                        kind: VariableKind::Let,
+                        declarations: vec![
+                            VariableDeclarator {
+                                id: Identifier {
+                                    name: String::from("a"),
+                                    span: Span::new(4, 5, 1, 5),
+                                },
+                                init: None,
+                                span: Span::new(4, 5, 1, 5),
+                            },
+                            VariableDeclarator {
+                                id: Identifier {
+                                    name: String::from("b"),
+                                    span: Span::new(7, 8, 1, 8),
+                                },
+                                init: Some(Expression {
+                                    kind: ExpressionKind::NumericLiteral {
+                                        raw: String::from("2"),
+                                        value: 2.0,
+                                    },
+                                    span: Span::new(11, 12, 1, 12),
+                                }),
+                                span: Span::new(7, 12, 1, 8),
+                            },
+                        ],
+                    },
+                    span: Span::new(0, 13, 1, 1),
+                }],
+                span: Span::new(0, 13, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_const_and_var_declarations() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("var v; const c = v;");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![
+                    Statement {
+                        kind: StatementKind::VariableDeclaration {
+                            kind: VariableKind::Var,
+                            declarations: vec![VariableDeclarator {
+                                id: Identifier {
+                                    name: String::from("v"),
+                                    span: Span::new(4, 5, 1, 5),
+                                },
+                                init: None,
+                                span: Span::new(4, 5, 1, 5),
+                            }],
+                        },
+                        span: Span::new(0, 6, 1, 1),
+                    },
+                    Statement {
+                        kind: StatementKind::VariableDeclaration {
+                            kind: VariableKind::Const,
+                            declarations: vec![VariableDeclarator {
+                                id: Identifier {
+                                    name: String::from("c"),
+                                    span: Span::new(13, 14, 1, 14),
+                                },
+                                init: Some(Expression {
+                                    kind: ExpressionKind::Identifier {
+                                        name: String::from("v"),
+                                    },
+                                    span: Span::new(17, 18, 1, 18),
+                                }),
+                                span: Span::new(13, 18, 1, 14),
+                            }],
+                        },
+                        span: Span::new(7, 19, 1, 8),
+                    },
+                ],
+                span: Span::new(0, 19, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_chained_assignment() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("x = y = 5;");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ExpressionStatement {
+                        expression: Expression {
+                            kind: ExpressionKind::AssignmentExpression {
+                                operator: AssignmentOp::Assign,
+                                left: Box::new(Expression {
+                                    kind: ExpressionKind::Identifier {
+                                        name: String::from("x"),
+                                    },
+                                    span: Span::new(0, 1, 1, 1),
+                                }),
+                                right: Box::new(Expression {
+                                    kind: ExpressionKind::AssignmentExpression {
+                                        operator: AssignmentOp::Assign,
+                                        left: Box::new(Expression {
+                                            kind: ExpressionKind::Identifier {
+                                                name: String::from("y"),
+                                            },
+                                            span: Span::new(4, 5, 1, 5),
+                                        }),
+                                        right: Box::new(Expression {
+                                            kind: ExpressionKind::NumericLiteral {
+                                                raw: String::from("5"),
+                                                value: 5.0,
+                                            },
+                                            span: Span::new(8, 9, 1, 9),
+                                        }),
+                                    },
+                                    span: Span::new(4, 9, 1, 5),
+                                }),
+                            },
+                            span: Span::new(0, 9, 1, 1),
+                        },
+                    },
+                    span: Span::new(0, 10, 1, 1),
+                }],
+                span: Span::new(0, 10, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_compound_assignment() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("x += 1;");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ExpressionStatement {
+                        expression: Expression {
+                            kind: ExpressionKind::AssignmentExpression {
+                                operator: AssignmentOp::AddAssign,
+                                left: Box::new(Expression {
+                                    kind: ExpressionKind::Identifier {
+                                        name: String::from("x"),
+                                    },
+                                    span: Span::new(0, 1, 1, 1),
+                                }),
+                                right: Box::new(Expression {
+                                    kind: ExpressionKind::NumericLiteral {
+                                        raw: String::from("1"),
+                                        value: 1.0,
+                                    },
+                                    span: Span::new(5, 6, 1, 6),
+                                }),
+                            },
+                            span: Span::new(0, 6, 1, 1),
+                        },
+                    },
+                    span: Span::new(0, 7, 1, 1),
+                }],
+                span: Span::new(0, 7, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_invalid_assignment_target() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("x + 1 = 2;");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Err(ParseError {
+                kind: ParseErrorKind::InvalidAssignmentTarget,
+                expected: vec![],
+                location: Span::new(0, 5, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_if_else_statement() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("if (x >= 1) x = 0; else { y; }");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::IfStatement {
+                        test: Expression {
+                            kind: ExpressionKind::BinaryExpression {
+                                operator: BinaryOp::GreaterThanOrEqual,
+                                left: Box::new(Expression {
+                                    kind: ExpressionKind::Identifier {
+                                        name: String::from("x"),
+                                    },
+                                    span: Span::new(4, 5, 1, 5),
+                                }),
+                                right: Box::new(Expression {
+                                    kind: ExpressionKind::NumericLiteral {
+                                        raw: String::from("1"),
+                                        value: 1.0,
+                                    },
+                                    span: Span::new(9, 10, 1, 10),
+                                }),
+                            },
+                            span: Span::new(4, 10, 1, 5),
+                        },
+                        consequent: Box::new(Statement {
+                            kind: StatementKind::ExpressionStatement {
+                                expression: Expression {
+                                    kind: ExpressionKind::AssignmentExpression {
+                                        operator: AssignmentOp::Assign,
+                                        left: Box::new(Expression {
+                                            kind: ExpressionKind::Identifier {
+                                                name: String::from("x"),
+                                            },
+                                            span: Span::new(12, 13, 1, 13),
+                                        }),
+                                        right: Box::new(Expression {
+                                            kind: ExpressionKind::NumericLiteral {
+                                                raw: String::from("0"),
+                                                value: 0.0,
+                                            },
+                                            span: Span::new(16, 17, 1, 17),
+                                        }),
+                                    },
+                                    span: Span::new(12, 17, 1, 13),
+                                },
+                            },
+                            span: Span::new(12, 18, 1, 13),
+                        }),
+                        alternate: Some(Box::new(Statement {
+                            kind: StatementKind::BlockStatement {
+                                body: vec![Statement {
+                                    kind: StatementKind::ExpressionStatement {
+                                        expression: Expression {
+                                            kind: ExpressionKind::Identifier {
+                                                name: String::from("y"),
+                                            },
+                                            span: Span::new(26, 27, 1, 27),
+                                        },
+                                    },
+                                    span: Span::new(26, 28, 1, 27),
+                                }],
+                            },
+                            span: Span::new(24, 30, 1, 25),
+                        })),
+                    },
+                    span: Span::new(0, 30, 1, 1),
+                }],
+                span: Span::new(0, 30, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_dangling_else() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("if (a) if (b) 1; else 2;");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::IfStatement {
+                        test: Expression {
+                            kind: ExpressionKind::Identifier {
+                                name: String::from("a"),
+                            },
+                            span: Span::new(4, 5, 1, 5),
+                        },
+                        consequent: Box::new(Statement {
+                            kind: StatementKind::IfStatement {
+                                test: Expression {
+                                    kind: ExpressionKind::Identifier {
+                                        name: String::from("b"),
+                                    },
+                                    span: Span::new(11, 12, 1, 12),
+                                },
+                                consequent: Box::new(Statement {
+                                    kind: StatementKind::ExpressionStatement {
+                                        expression: Expression {
+                                            kind: ExpressionKind::NumericLiteral {
+                                                raw: String::from("1"),
+                                                value: 1.0,
+                                            },
+                                            span: Span::new(14, 15, 1, 15),
+                                        },
+                                    },
+                                    span: Span::new(14, 16, 1, 15),
+                                }),
+                                alternate: Some(Box::new(Statement {
+                                    kind: StatementKind::ExpressionStatement {
+                                        expression: Expression {
+                                            kind: ExpressionKind::NumericLiteral {
+                                                raw: String::from("2"),
+                                                value: 2.0,
+                                            },
+                                            span: Span::new(22, 23, 1, 23),
+                                        },
+                                    },
+                                    span: Span::new(22, 24, 1, 23),
+                                })),
+                            },
+                            span: Span::new(7, 24, 1, 8),
+                        }),
+                        alternate: None,
+                    },
+                    span: Span::new(0, 24, 1, 1),
+                }],
+                span: Span::new(0, 24, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_while_statement() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("while (x < 10) x += 1;");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::WhileStatement {
+                        test: Expression {
+                            kind: ExpressionKind::BinaryExpression {
+                                operator: BinaryOp::LessThan,
+                                left: Box::new(Expression {
+                                    kind: ExpressionKind::Identifier {
+                                        name: String::from("x"),
+                                    },
+                                    span: Span::new(7, 8, 1, 8),
+                                }),
+                                right: Box::new(Expression {
+                                    kind: ExpressionKind::NumericLiteral {
+                                        raw: String::from("10"),
+                                        value: 10.0,
+                                    },
+                                    span: Span::new(11, 13, 1, 12),
+                                }),
+                            },
+                            span: Span::new(7, 13, 1, 8),
+                        },
+                        body: Box::new(Statement {
+                            kind: StatementKind::ExpressionStatement {
+                                expression: Expression {
+                                    kind: ExpressionKind::AssignmentExpression {
+                                        operator: AssignmentOp::AddAssign,
+                                        left: Box::new(Expression {
+                                            kind: ExpressionKind::Identifier {
+                                                name: String::from("x"),
+                                            },
+                                            span: Span::new(15, 16, 1, 16),
+                                        }),
+                                        right: Box::new(Expression {
+                                            kind: ExpressionKind::NumericLiteral {
+                                                raw: String::from("1"),
+                                                value: 1.0,
+                                            },
+                                            span: Span::new(20, 21, 1, 21),
+                                        }),
+                                    },
+                                    span: Span::new(15, 21, 1, 16),
+                                },
+                            },
+                            span: Span::new(15, 22, 1, 16),
+                        }),
+                    },
+                    span: Span::new(0, 22, 1, 1),
+                }],
+                span: Span::new(0, 22, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_do_while_statement() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("do { x; } while (x);");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::DoWhileStatement {
+                        body: Box::new(Statement {
+                            kind: StatementKind::BlockStatement {
+                                body: vec![Statement {
+                                    kind: StatementKind::ExpressionStatement {
+                                        expression: Expression {
+                                            kind: ExpressionKind::Identifier {
+                                                name: String::from("x"),
+                                            },
+                                            span: Span::new(5, 6, 1, 6),
+                                        },
+                                    },
+                                    span: Span::new(5, 7, 1, 6),
+                                }],
+                            },
+                            span: Span::new(3, 9, 1, 4),
+                        }),
+                        test: Expression {
+                            kind: ExpressionKind::Identifier {
+                                name: String::from("x"),
+                            },
+                            span: Span::new(17, 18, 1, 18),
+                        },
+                    },
+                    span: Span::new(0, 20, 1, 1),
+                }],
+                span: Span::new(0, 20, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_for_statement() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("for (let i = 0; i < 10; i += 1) {}");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ForStatement {
+                        init: Some(ForInit::VariableDeclaration(Box::new(Statement {
+                            kind: StatementKind::VariableDeclaration {
+                                kind: VariableKind::Let,
+                                declarations: vec![VariableDeclarator {
+                                    id: Identifier {
+                                        name: String::from("i"),
+                                        span: Span::new(9, 10, 1, 10),
+                                    },
+                                    init: Some(Expression {
+                                        kind: ExpressionKind::NumericLiteral {
+                                            raw: String::from("0"),
+                                            value: 0.0,
+                                        },
+                                        span: Span::new(13, 14, 1, 14),
+                                    }),
+                                    span: Span::new(9, 14, 1, 10),
+                                }],
+                            },
+                            span: Span::new(5, 14, 1, 6),
+                        }))),
+                        test: Some(Expression {
+                            kind: ExpressionKind::BinaryExpression {
+                                operator: BinaryOp::LessThan,
+                                left: Box::new(Expression {
+                                    kind: ExpressionKind::Identifier {
+                                        name: String::from("i"),
+                                    },
+                                    span: Span::new(16, 17, 1, 17),
+                                }),
+                                right: Box::new(Expression {
+                                    kind: ExpressionKind::NumericLiteral {
+                                        raw: String::from("10"),
+                                        value: 10.0,
+                                    },
+                                    span: Span::new(20, 22, 1, 21),
+                                }),
+                            },
+                            span: Span::new(16, 22, 1, 17),
+                        }),
+                        update: Some(Expression {
+                            kind: ExpressionKind::AssignmentExpression {
+                                operator: AssignmentOp::AddAssign,
+                                left: Box::new(Expression {
+                                    kind: ExpressionKind::Identifier {
+                                        name: String::from("i"),
+                                    },
+                                    span: Span::new(24, 25, 1, 25),
+                                }),
+                                right: Box::new(Expression {
+                                    kind: ExpressionKind::NumericLiteral {
+                                        raw: String::from("1"),
+                                        value: 1.0,
+                                    },
+                                    span: Span::new(29, 30, 1, 30),
+                                }),
+                            },
+                            span: Span::new(24, 30, 1, 25),
+                        }),
+                        body: Box::new(Statement {
+                            kind: StatementKind::BlockStatement { body: vec![] },
+                            span: Span::new(32, 34, 1, 33),
+                        }),
+                    },
+                    span: Span::new(0, 34, 1, 1),
+                }],
+                span: Span::new(0, 34, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_for_statement_empty_clauses() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("for (;;) {}");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ForStatement {
+                        init: None,
+                        test: None,
+                        update: None,
+                        body: Box::new(Statement {
+                            kind: StatementKind::BlockStatement { body: vec![] },
+                            span: Span::new(9, 11, 1, 10),
+                        }),
+                    },
+                    span: Span::new(0, 11, 1, 1),
+                }],
+                span: Span::new(0, 11, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_function_declaration() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("function square(x, y) { return x * y; }");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::FunctionDeclaration {
+                        id: Identifier {
+                            name: String::from("square"),
+                            span: Span::new(9, 15, 1, 10),
+                        },
+                        params: vec![
+                            Identifier {
+                                name: String::from("x"),
+                                span: Span::new(16, 17, 1, 17),
+                            },
+                            Identifier {
+                                name: String::from("y"),
+                                span: Span::new(19, 20, 1, 20),
+                            },
+                        ],
+                        body: Box::new(Statement {
+                            kind: StatementKind::BlockStatement {
+                                body: vec![Statement {
+                                    kind: StatementKind::ReturnStatement {
+                                        argument: Some(Expression {
+                                            kind: ExpressionKind::BinaryExpression {
+                                                operator: BinaryOp::Multiply,
+                                                left: Box::new(Expression {
+                                                    kind: ExpressionKind::Identifier {
+                                                        name: String::from("x"),
+                                                    },
+                                                    span: Span::new(31, 32, 1, 32),
+                                                }),
+                                                right: Box::new(Expression {
+                                                    kind: ExpressionKind::Identifier {
+                                                        name: String::from("y"),
+                                                    },
+                                                    span: Span::new(35, 36, 1, 36),
+                                                }),
+                                            },
+                                            span: Span::new(31, 36, 1, 32),
+                                        }),
+                                    },
+                                    span: Span::new(24, 37, 1, 25),
+                                }],
+                            },
+                            span: Span::new(22, 39, 1, 23),
+                        }),
+                    },
+                    span: Span::new(0, 39, 1, 1),
+                }],
+                span: Span::new(0, 39, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_empty_return() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("function f() { return; }");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::FunctionDeclaration {
+                        id: Identifier {
+                            name: String::from("f"),
+                            span: Span::new(9, 10, 1, 10),
+                        },
+                        params: vec![],
+                        body: Box::new(Statement {
+                            kind: StatementKind::BlockStatement {
+                                body: vec![Statement {
+                                    kind: StatementKind::ReturnStatement { argument: None },
+                                    span: Span::new(15, 22, 1, 16),
+                                }],
+                            },
+                            span: Span::new(13, 24, 1, 14),
+                        }),
+                    },
+                    span: Span::new(0, 24, 1, 1),
+                }],
+                span: Span::new(0, 24, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_return_outside_function() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("return 1;");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Err(ParseError {
+                kind: ParseErrorKind::ReturnOutsideFunction,
+                expected: vec![],
+                location: Span::new(0, 6, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_class_declaration() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from(
+            "class Point extends Base { constructor(x) { super(x); this.x = x; } method() {} }",
+        );
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ClassDeclaration {
+                        id: identifier("Point", 6),
+                        super_class: Some(reference("Base", 20)),
+                        body: vec![
+                            MethodDefinition {
+                                key: identifier("constructor", 27),
+                                kind: MethodKind::Constructor,
+                                params: vec![identifier("x", 39)],
+                                body: Box::new(Statement {
+                                    kind: StatementKind::BlockStatement {
+                                        body: vec![
+                                            Statement {
+                                                kind: StatementKind::ExpressionStatement {
+                                                    expression: Expression {
+                                                        kind: ExpressionKind::CallExpression {
+                                                            callee: Box::new(Expression {
+                                                                kind: ExpressionKind::Super,
+                                                                span: Span::new(44, 49, 1, 45),
+                                                            }),
+                                                            arguments: vec![reference("x", 50)],
+                                                        },
+                                                        span: Span::new(44, 52, 1, 45),
+                                                    },
+                                                },
+                                                span: Span::new(44, 53, 1, 45),
+                                            },
+                                            Statement {
+                                                kind: StatementKind::ExpressionStatement {
+                                                    expression: Expression {
+                                                        kind: ExpressionKind::AssignmentExpression {
+                                                            operator: AssignmentOp::Assign,
+                                                            left: Box::new(Expression {
+                                                                kind: ExpressionKind::MemberExpression {
+                                                                    object: Box::new(Expression {
+                                                                        kind: ExpressionKind::ThisExpression,
+                                                                        span: Span::new(54, 58, 1, 55),
+                                                                    }),
+                                                                    property: Box::new(reference("x", 59)),
+                                                                    computed: false,
+                                                                },
+                                                                span: Span::new(54, 60, 1, 55),
+                                                            }),
+                                                            right: Box::new(reference("x", 63)),
+                                                        },
+                                                        span: Span::new(54, 64, 1, 55),
+                                                    },
+                                                },
+                                                span: Span::new(54, 65, 1, 55),
+                                            },
+                                        ],
+                                    },
+                                    span: Span::new(42, 67, 1, 43),
+                                }),
+                                span: Span::new(27, 67, 1, 28),
+                            },
+                            MethodDefinition {
+                                key: identifier("method", 68),
+                                kind: MethodKind::Method,
+                                params: vec![],
+                                body: Box::new(Statement {
+                                    kind: StatementKind::BlockStatement { body: vec![] },
+                                    span: Span::new(77, 79, 1, 78),
+                                }),
+                                span: Span::new(68, 79, 1, 69),
+                            },
+                        ],
+                    },
+                    span: Span::new(0, 81, 1, 1),
+                }],
+                span: Span::new(0, 81, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_new_expression_binds_first_arguments() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("new a.B(1).c;");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ExpressionStatement {
+                        expression: Expression {
+                            kind: ExpressionKind::MemberExpression {
+                                object: Box::new(Expression {
+                                    kind: ExpressionKind::NewExpression {
+                                        callee: Box::new(Expression {
+                                            kind: ExpressionKind::MemberExpression {
+                                                object: Box::new(reference("a", 4)),
+                                                property: Box::new(reference("B", 6)),
+                                                computed: false,
+                                            },
+                                            span: Span::new(4, 7, 1, 5),
+                                        }),
+                                        arguments: vec![Expression {
+                                            kind: ExpressionKind::NumericLiteral {
+                                                raw: String::from("1"),
+                                                value: 1.0,
+                                            },
+                                            span: Span::new(8, 9, 1, 9),
+                                        }],
+                                    },
+                                    span: Span::new(0, 10, 1, 1),
+                                }),
+                                property: Box::new(reference("c", 11)),
+                                computed: false,
+                            },
+                            span: Span::new(0, 12, 1, 1),
+                        },
+                    },
+                    span: Span::new(0, 13, 1, 1),
+                }],
+                span: Span::new(0, 13, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_arrow_functions() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("(a, b) => a + b; x => { return x; };");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![
+                    Statement {
+                        kind: StatementKind::ExpressionStatement {
+                            expression: Expression {
+                                kind: ExpressionKind::ArrowFunctionExpression {
+                                    params: vec![identifier("a", 1), identifier("b", 4)],
+                                    body: ArrowBody::Expression(Box::new(Expression {
+                                        kind: ExpressionKind::BinaryExpression {
+                                            operator: BinaryOp::Add,
+                                            left: Box::new(reference("a", 10)),
+                                            right: Box::new(reference("b", 14)),
+                                        },
+                                        span: Span::new(10, 15, 1, 11),
+                                    })),
+                                },
+                                span: Span::new(0, 15, 1, 1),
+                            },
+                        },
+                        span: Span::new(0, 16, 1, 1),
+                    },
+                    Statement {
+                        kind: StatementKind::ExpressionStatement {
+                            expression: Expression {
+                                kind: ExpressionKind::ArrowFunctionExpression {
+                                    params: vec![identifier("x", 17)],
+                                    body: ArrowBody::Block(Box::new(Statement {
+                                        kind: StatementKind::BlockStatement {
+                                            body: vec![Statement {
+                                                kind: StatementKind::ReturnStatement {
+                                                    argument: Some(reference("x", 31)),
+                                                },
+                                                span: Span::new(24, 33, 1, 25),
+                                            }],
+                                        },
+                                        span: Span::new(22, 35, 1, 23),
+                                    })),
+                                },
+                                span: Span::new(17, 35, 1, 18),
+                            },
+                        },
+                        span: Span::new(17, 36, 1, 18),
+                    },
+                ],
+                span: Span::new(0, 36, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_function_expression_arguments() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("f(function (x) {}, () => 1);");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ExpressionStatement {
+                        expression: Expression {
+                            kind: ExpressionKind::CallExpression {
+                                callee: Box::new(Expression {
+                                    kind: ExpressionKind::Identifier {
+                                        name: String::from("f"),
+                                    },
+                                    span: Span::new(0, 1, 1, 1),
+                                }),
+                                arguments: vec![
+                                    Expression {
+                                        kind: ExpressionKind::FunctionExpression {
+                                            id: None,
+                                            params: vec![Identifier {
+                                                name: String::from("x"),
+                                                span: Span::new(12, 13, 1, 13),
+                                            }],
+                                            body: Box::new(Statement {
+                                                kind: StatementKind::BlockStatement {
+                                                    body: vec![],
+                                                },
+                                                span: Span::new(15, 17, 1, 16),
+                                            }),
+                                        },
+                                        span: Span::new(2, 17, 1, 3),
+                                    },
+                                    Expression {
+                                        kind: ExpressionKind::ArrowFunctionExpression {
+                                            params: vec![],
+                                            body: ArrowBody::Expression(Box::new(Expression {
+                                                kind: ExpressionKind::NumericLiteral {
+                                                    raw: String::from("1"),
+                                                    value: 1.0,
+                                                },
+                                                span: Span::new(25, 26, 1, 26),
+                                            })),
+                                        },
+                                        span: Span::new(19, 26, 1, 20),
+                                    },
+                                ],
+                            },
+                            span: Span::new(0, 27, 1, 1),
+                        },
+                    },
+                    span: Span::new(0, 28, 1, 1),
+                }],
+                span: Span::new(0, 28, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_keyword_property_name() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("map.delete(k);");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ExpressionStatement {
+                        expression: Expression {
+                            kind: ExpressionKind::CallExpression {
+                                callee: Box::new(Expression {
+                                    kind: ExpressionKind::MemberExpression {
+                                        object: Box::new(reference("map", 0)),
+                                        property: Box::new(reference("delete", 4)),
+                                        computed: false,
+                                    },
+                                    span: Span::new(0, 10, 1, 1),
+                                }),
+                                arguments: vec![reference("k", 11)],
+                            },
+                            span: Span::new(0, 13, 1, 1),
+                        },
+                    },
+                    span: Span::new(0, 14, 1, 1),
+                }],
+                span: Span::new(0, 14, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_keyword_keys_and_method_names() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("x = { new: 1 }; class A { delete() {} } a.class;");
+
+        let ast = parser.parse(program);
+
+        assert!(ast.is_ok(), "{:?}", ast);
+    }
+
+    #[test]
+    fn test_chained_call_member_expression() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("obj.method(1)(2)[k];");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ExpressionStatement {
+                        expression: Expression {
+                            kind: ExpressionKind::MemberExpression {
+                                object: Box::new(Expression {
+                                    kind: ExpressionKind::CallExpression {
+                                        callee: Box::new(Expression {
+                                            kind: ExpressionKind::CallExpression {
+                                                callee: Box::new(Expression {
+                                                    kind: ExpressionKind::MemberExpression {
+                                                        object: Box::new(Expression {
+                                                            kind: ExpressionKind::Identifier {
+                                                                name: String::from("obj"),
+                                                            },
+                                                            span: Span::new(0, 3, 1, 1),
+                                                        }),
+                                                        property: Box::new(Expression {
+                                                            kind: ExpressionKind::Identifier {
+                                                                name: String::from("method"),
+                                                            },
+                                                            span: Span::new(4, 10, 1, 5),
+                                                        }),
+                                                        computed: false,
+                                                    },
+                                                    span: Span::new(0, 10, 1, 1),
+                                                }),
+                                                arguments: vec![Expression {
+                                                    kind: ExpressionKind::NumericLiteral {
+                                                        raw: String::from("1"),
+                                                        value: 1.0,
+                                                    },
+                                                    span: Span::new(11, 12, 1, 12),
+                                                }],
+                                            },
+                                            span: Span::new(0, 13, 1, 1),
+                                        }),
+                                        arguments: vec![Expression {
+                                            kind: ExpressionKind::NumericLiteral {
+                                                raw: String::from("2"),
+                                                value: 2.0,
+                                            },
+                                            span: Span::new(14, 15, 1, 15),
+                                        }],
+                                    },
+                                    span: Span::new(0, 16, 1, 1),
+                                }),
+                                property: Box::new(Expression {
+                                    kind: ExpressionKind::Identifier {
+                                        name: String::from("k"),
+                                    },
+                                    span: Span::new(17, 18, 1, 18),
+                                }),
+                                computed: true,
+                            },
+                            span: Span::new(0, 19, 1, 1),
+                        },
+                    },
+                    span: Span::new(0, 20, 1, 1),
+                }],
+                span: Span::new(0, 20, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_member_assignment_with_call() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("a.b = f(x, y);");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ExpressionStatement {
+                        expression: Expression {
+                            kind: ExpressionKind::AssignmentExpression {
+                                operator: AssignmentOp::Assign,
+                                left: Box::new(Expression {
+                                    kind: ExpressionKind::MemberExpression {
+                                        object: Box::new(Expression {
+                                            kind: ExpressionKind::Identifier {
+                                                name: String::from("a"),
+                                            },
+                                            span: Span::new(0, 1, 1, 1),
+                                        }),
+                                        property: Box::new(Expression {
+                                            kind: ExpressionKind::Identifier {
+                                                name: String::from("b"),
+                                            },
+                                            span: Span::new(2, 3, 1, 3),
+                                        }),
+                                        computed: false,
+                                    },
+                                    span: Span::new(0, 3, 1, 1),
+                                }),
+                                right: Box::new(Expression {
+                                    kind: ExpressionKind::CallExpression {
+                                        callee: Box::new(Expression {
+                                            kind: ExpressionKind::Identifier {
+                                                name: String::from("f"),
+                                            },
+                                            span: Span::new(6, 7, 1, 7),
+                                        }),
+                                        arguments: vec![
+                                            Expression {
+                                                kind: ExpressionKind::Identifier {
+                                                    name: String::from("x"),
+                                                },
+                                                span: Span::new(8, 9, 1, 9),
+                                            },
+                                            Expression {
+                                                kind: ExpressionKind::Identifier {
+                                                    name: String::from("y"),
+                                                },
+                                                span: Span::new(11, 12, 1, 12),
+                                            },
+                                        ],
+                                    },
+                                    span: Span::new(6, 13, 1, 7),
+                                }),
+                            },
+                            span: Span::new(0, 13, 1, 1),
+                        },
+                    },
+                    span: Span::new(0, 14, 1, 1),
+                }],
+                span: Span::new(0, 14, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_unary_precedence() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("-x * +2;");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ExpressionStatement {
+                        expression: Expression {
+                            kind: ExpressionKind::BinaryExpression {
+                                operator: BinaryOp::Multiply,
+                                left: Box::new(Expression {
+                                    kind: ExpressionKind::UnaryExpression {
+                                        operator: UnaryOp::Minus,
+                                        argument: Box::new(Expression {
+                                            kind: ExpressionKind::Identifier {
+                                                name: String::from("x"),
+                                            },
+                                            span: Span::new(1, 2, 1, 2),
+                                        }),
+                                    },
+                                    span: Span::new(0, 2, 1, 1),
+                                }),
+                                right: Box::new(Expression {
+                                    kind: ExpressionKind::UnaryExpression {
+                                        operator: UnaryOp::Plus,
+                                        argument: Box::new(Expression {
+                                            kind: ExpressionKind::NumericLiteral {
+                                                raw: String::from("2"),
+                                                value: 2.0,
+                                            },
+                                            span: Span::new(6, 7, 1, 7),
+                                        }),
+                                    },
+                                    span: Span::new(5, 7, 1, 6),
+                                }),
+                            },
+                            span: Span::new(0, 7, 1, 1),
+                        },
+                    },
+                    span: Span::new(0, 8, 1, 1),
+                }],
+                span: Span::new(0, 8, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_nested_unary_expressions() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("!!x; - -x; typeof x;");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![
+                    Statement {
+                        kind: StatementKind::ExpressionStatement {
+                            expression: Expression {
+                                kind: ExpressionKind::UnaryExpression {
+                                    operator: UnaryOp::Not,
+                                    argument: Box::new(Expression {
+                                        kind: ExpressionKind::UnaryExpression {
+                                            operator: UnaryOp::Not,
+                                            argument: Box::new(Expression {
+                                                kind: ExpressionKind::Identifier {
+                                                    name: String::from("x"),
+                                                },
+                                                span: Span::new(2, 3, 1, 3),
+                                            }),
+                                        },
+                                        span: Span::new(1, 3, 1, 2),
+                                    }),
+                                },
+                                span: Span::new(0, 3, 1, 1),
+                            },
+                        },
+                        span: Span::new(0, 4, 1, 1),
+                    },
+                    Statement {
+                        kind: StatementKind::ExpressionStatement {
+                            expression: Expression {
+                                kind: ExpressionKind::UnaryExpression {
+                                    operator: UnaryOp::Minus,
+                                    argument: Box::new(Expression {
+                                        kind: ExpressionKind::UnaryExpression {
+                                            operator: UnaryOp::Minus,
+                                            argument: Box::new(Expression {
+                                                kind: ExpressionKind::Identifier {
+                                                    name: String::from("x"),
+                                                },
+                                                span: Span::new(8, 9, 1, 9),
+                                            }),
+                                        },
+                                        span: Span::new(7, 9, 1, 8),
+                                    }),
+                                },
+                                span: Span::new(5, 9, 1, 6),
+                            },
+                        },
+                        span: Span::new(5, 10, 1, 6),
+                    },
+                    Statement {
+                        kind: StatementKind::ExpressionStatement {
+                            expression: Expression {
+                                kind: ExpressionKind::UnaryExpression {
+                                    operator: UnaryOp::Typeof,
+                                    argument: Box::new(Expression {
+                                        kind: ExpressionKind::Identifier {
+                                            name: String::from("x"),
+                                        },
+                                        span: Span::new(18, 19, 1, 19),
+                                    }),
+                                },
+                                span: Span::new(11, 19, 1, 12),
+                            },
+                        },
+                        span: Span::new(11, 20, 1, 12),
+                    },
+                ],
+                span: Span::new(0, 20, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_logical_precedence() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("a || b && c === d;");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ExpressionStatement {
+                        expression: Expression {
+                            kind: ExpressionKind::LogicalExpression {
+                                operator: LogicalOp::Or,
+                                left: Box::new(Expression {
+                                    kind: ExpressionKind::Identifier {
+                                        name: String::from("a"),
+                                    },
+                                    span: Span::new(0, 1, 1, 1),
+                                }),
+                                right: Box::new(Expression {
+                                    kind: ExpressionKind::LogicalExpression {
+                                        operator: LogicalOp::And,
+                                        left: Box::new(Expression {
+                                            kind: ExpressionKind::Identifier {
+                                                name: String::from("b"),
+                                            },
+                                            span: Span::new(5, 6, 1, 6),
+                                        }),
+                                        right: Box::new(Expression {
+                                            kind: ExpressionKind::BinaryExpression {
+                                                operator: BinaryOp::StrictEqual,
+                                                left: Box::new(Expression {
+                                                    kind: ExpressionKind::Identifier {
+                                                        name: String::from("c"),
+                                                    },
+                                                    span: Span::new(10, 11, 1, 11),
+                                                }),
+                                                right: Box::new(Expression {
+                                                    kind: ExpressionKind::Identifier {
+                                                        name: String::from("d"),
+                                                    },
+                                                    span: Span::new(16, 17, 1, 17),
+                                                }),
+                                            },
+                                            span: Span::new(10, 17, 1, 11),
+                                        }),
+                                    },
+                                    span: Span::new(5, 17, 1, 6),
+                                }),
+                            },
+                            span: Span::new(0, 17, 1, 1),
+                        },
+                    },
+                    span: Span::new(0, 18, 1, 1),
+                }],
+                span: Span::new(0, 18, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_equality_left_associativity() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("x != y == z;");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ExpressionStatement {
+                        expression: Expression {
+                            kind: ExpressionKind::BinaryExpression {
+                                operator: BinaryOp::Equal,
+                                left: Box::new(Expression {
+                                    kind: ExpressionKind::BinaryExpression {
+                                        operator: BinaryOp::NotEqual,
+                                        left: Box::new(Expression {
+                                            kind: ExpressionKind::Identifier {
+                                                name: String::from("x"),
+                                            },
+                                            span: Span::new(0, 1, 1, 1),
+                                        }),
+                                        right: Box::new(Expression {
+                                            kind: ExpressionKind::Identifier {
+                                                name: String::from("y"),
+                                            },
+                                            span: Span::new(5, 6, 1, 6),
+                                        }),
+                                    },
+                                    span: Span::new(0, 6, 1, 1),
+                                }),
+                                right: Box::new(Expression {
+                                    kind: ExpressionKind::Identifier {
+                                        name: String::from("z"),
+                                    },
+                                    span: Span::new(10, 11, 1, 11),
+                                }),
+                            },
+                            span: Span::new(0, 11, 1, 1),
+                        },
+                    },
+                    span: Span::new(0, 12, 1, 1),
+                }],
+                span: Span::new(0, 12, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_array_literal_holes_and_trailing_comma() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("[1, , 2,];");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ExpressionStatement {
+                        expression: Expression {
+                            kind: ExpressionKind::ArrayExpression {
+                                elements: vec![Some(number("1", 1.0, 1)), None, Some(number("2", 2.0, 6))],
+                            },
+                            span: Span::new(0, 9, 1, 1),
+                        },
+                    },
+                    span: Span::new(0, 10, 1, 1),
+                }],
+                span: Span::new(0, 10, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_object_literal() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("x = { key: 1, \"quoted\": 2, [k]: 3 };");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ExpressionStatement {
+                        expression: Expression {
+                            kind: ExpressionKind::AssignmentExpression {
+                                operator: AssignmentOp::Assign,
+                                left: Box::new(reference("x", 0)),
+                                right: Box::new(Expression {
+                                    kind: ExpressionKind::ObjectExpression {
+                                        properties: vec![
+                                            Property {
+                                                key: reference("key", 6),
+                                                value: number("1", 1.0, 11),
+                                                computed: false,
+                                                span: Span::new(6, 12, 1, 7),
+                                            },
+                                            Property {
+                                                key: Expression {
+                                                    kind: ExpressionKind::StringLiteral {
+                                                        value: String::from("quoted"),
+                                                    },
+                                                    span: Span::new(14, 22, 1, 15),
+                                                },
+                                                value: number("2", 2.0, 24),
+                                                computed: false,
+                                                span: Span::new(14, 25, 1, 15),
+                                            },
+                                            Property {
+                                                key: reference("k", 28),
+                                                value: number("3", 3.0, 32),
+                                                computed: true,
+                                                span: Span::new(27, 33, 1, 28),
+                                            },
+                                        ],
+                                    },
+                                    span: Span::new(4, 35, 1, 5),
+                                }),
+                            },
+                            span: Span::new(0, 35, 1, 1),
+                        },
+                    },
+                    span: Span::new(0, 36, 1, 1),
+                }],
+                span: Span::new(0, 36, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_block_versus_object_literal() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("{} ({});");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![
+                    Statement {
+                        kind: StatementKind::BlockStatement { body: vec![] },
+                        span: Span::new(0, 2, 1, 1),
+                    },
+                    Statement {
+                        kind: StatementKind::ExpressionStatement {
+                            expression: Expression {
+                                kind: ExpressionKind::ObjectExpression { properties: vec![] },
+                                span: Span::new(3, 7, 1, 4),
+                            },
+                        },
+                        span: Span::new(3, 8, 1, 4),
+                    },
+                ],
+                span: Span::new(0, 8, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_nested_conditional_expression() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("a ? b : c ? d : e;");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ExpressionStatement {
+                        expression: Expression {
+                            kind: ExpressionKind::ConditionalExpression {
+                                test: Box::new(reference("a", 0)),
+                                consequent: Box::new(reference("b", 4)),
+                                alternate: Box::new(Expression {
+                                    kind: ExpressionKind::ConditionalExpression {
+                                        test: Box::new(reference("c", 8)),
+                                        consequent: Box::new(reference("d", 12)),
+                                        alternate: Box::new(reference("e", 16)),
+                                    },
+                                    span: Span::new(8, 17, 1, 9),
+                                }),
+                            },
+                            span: Span::new(0, 17, 1, 1),
+                        },
+                    },
+                    span: Span::new(0, 18, 1, 1),
+                }],
+                span: Span::new(0, 18, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_conditional_below_assignment() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("x = a || b ? 1 : 2;");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ExpressionStatement {
+                        expression: Expression {
+                            kind: ExpressionKind::AssignmentExpression {
+                                operator: AssignmentOp::Assign,
+                                left: Box::new(reference("x", 0)),
+                                right: Box::new(Expression {
+                                    kind: ExpressionKind::ConditionalExpression {
+                                        test: Box::new(Expression {
+                                            kind: ExpressionKind::LogicalExpression {
+                                                operator: LogicalOp::Or,
+                                                left: Box::new(reference("a", 4)),
+                                                right: Box::new(reference("b", 9)),
+                                            },
+                                            span: Span::new(4, 10, 1, 5),
+                                        }),
+                                        consequent: Box::new(number("1", 1.0, 13)),
+                                        alternate: Box::new(number("2", 2.0, 17)),
+                                    },
+                                    span: Span::new(4, 18, 1, 5),
+                                }),
+                            },
+                            span: Span::new(0, 18, 1, 1),
+                        },
+                    },
+                    span: Span::new(0, 19, 1, 1),
+                }],
+                span: Span::new(0, 19, 1, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_binary_operand_types() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("2 + \"2\"; 2 + 2;");
+
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![
+                    Statement {
+                        kind: StatementKind::ExpressionStatement {
+                            expression: Expression {
+                                kind: ExpressionKind::BinaryExpression {
+                                    operator: BinaryOp::Add,
+                                    left: Box::new(Expression {
+                                        kind: ExpressionKind::NumericLiteral {
+                                            raw: String::from("2"),
+                                            value: 2.0,
+                                        },
+                                        span: Span::new(0, 1, 1, 1),
+                                    }),
+                                    right: Box::new(Expression {
+                                        kind: ExpressionKind::StringLiteral {
+                                            value: String::from("2"),
+                                        },
+                                        span: Span::new(4, 7, 1, 5),
+                                    }),
+                                },
+                                span: Span::new(0, 7, 1, 1),
+                            },
+                        },
+                        span: Span::new(0, 8, 1, 1),
+                    },
+                    Statement {
+                        kind: StatementKind::ExpressionStatement {
+                            expression: Expression {
+                                kind: ExpressionKind::BinaryExpression {
+                                    operator: BinaryOp::Add,
+                                    left: Box::new(Expression {
+                                        kind: ExpressionKind::NumericLiteral {
+                                            raw: String::from("2"),
+                                            value: 2.0,
+                                        },
+                                        span: Span::new(9, 10, 1, 10),
+                                    }),
+                                    right: Box::new(Expression {
+                                        kind: ExpressionKind::NumericLiteral {
+                                            raw: String::from("2"),
+                                            value: 2.0,
+                                        },
+                                        span: Span::new(13, 14, 1, 14),
+                                    }),
+                                },
+                                span: Span::new(9, 14, 1, 10),
+                            },
+                        },
+                        span: Span::new(9, 15, 1, 10),
+                    },
+                ],
+                span: Span::new(0, 15, 1, 1),
+            })
+        );
     }
 
-    /// Parses a string into an AST.
-    fn parse(&mut self, string: String) -> Option<Literal> {
-        self.string = string.clone();
-        self.tokenizer.string = LiteralType::Type(string.clone());
+    #[test]
+    fn test_custom_right_associative_operator() {
+        let mut parser =
+            Parser::with_operators(vec![InfixOperator::custom("**", 7, Associativity::Right)]);
 
-        // Prime the tokenizer to obtain the first token
-        // which is our lookahead for predictive parsing.
-        self.lookahead = self.tokenizer.get_next_token();
+        let program: String = String::from("1 + 2 ** 3 ** 2;");
 
-        self.program()
-    }
+        let ast = parser.parse(program);
 
-    /// Main Entry Point
-    ///
-    /// Program
-    ///   : NumericLiteral
-    ///   ;
-    fn program(&mut self) -> Option<Literal> {
-        Some(Literal {
-            literal_type: LiteralType::Type(String::from("Program")),
-            value: Box::new(LiteralValue::NestedValueList(self.statement_list(None))),
-        })
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ExpressionStatement {
+                        expression: Expression {
+                            kind: ExpressionKind::BinaryExpression {
+                                operator: BinaryOp::Add,
+                                left: Box::new(number("1", 1.0, 0)),
+                                right: Box::new(Expression {
+                                    kind: ExpressionKind::BinaryExpression {
+                                        operator: BinaryOp::Custom("**"),
+                                        left: Box::new(number("2", 2.0, 4)),
+                                        right: Box::new(Expression {
+                                            kind: ExpressionKind::BinaryExpression {
+                                                operator: BinaryOp::Custom("**"),
+                                                left: Box::new(number("3", 3.0, 9)),
+                                                right: Box::new(number("2", 2.0, 14)),
+                                            },
+                                            span: Span::new(9, 15, 1, 10),
+                                        }),
+                                    },
+                                    span: Span::new(4, 15, 1, 5),
+                                }),
+                            },
+                            span: Span::new(0, 15, 1, 1),
+                        },
+                    },
+                    span: Span::new(0, 16, 1, 1),
+                }],
+                span: Span::new(0, 16, 1, 1),
+            })
+        );
     }
 
-    /// Literal
-    ///   ; NumericLiteral
-    ///   | StringLiteral
-    ///   ;
-    fn literal(&mut self) -> Option<Literal> {
-        return match self.lookahead.clone()?.literal_type {
-            LiteralType::Type(ref string) => match string.as_str() {
-                "NUMBER" => Some(self.numeric_literal()),
-                "STRING" => Some(self.string_literal()),
-                _ => panic!("Literal: unexpected literal production."),
-            },
-        };
-    }
+    #[test]
+    fn test_custom_operator_highest_precedence() {
+        let mut parser =
+            Parser::with_operators(vec![InfixOperator::custom("**", 255, Associativity::Left)]);
 
-    /// Statement List
-    ///   : Statement
-    ///   | StatementList Statement -> Statement Statement Statement Statement
-    ///   ;
-    fn statement_list(
-        &mut self,
-        stop_lookahead: Option<Box<LiteralValue>>,
-    ) -> Vec<Option<Literal>> {
-        let mut statement_list = vec![self.statement()];
-        while let Some(ref lookahead) = self.lookahead {
-            if let Some(ref stop_lookahead) = stop_lookahead {
-                if lookahead.value == *stop_lookahead {
-                    break;
-                }
-            }
-            statement_list.push(self.statement());
-        }
+        let program: String = String::from("a ** b ** c;");
 
-        statement_list
+        let ast = parser.parse(program);
+
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ExpressionStatement {
+                        expression: Expression {
+                            kind: ExpressionKind::BinaryExpression {
+                                operator: BinaryOp::Custom("**"),
+                                left: Box::new(Expression {
+                                    kind: ExpressionKind::BinaryExpression {
+                                        operator: BinaryOp::Custom("**"),
+                                        left: Box::new(reference("a", 0)),
+                                        right: Box::new(reference("b", 5)),
+                                    },
+                                    span: Span::new(0, 6, 1, 1),
+                                }),
+                                right: Box::new(reference("c", 10)),
+                            },
+                            span: Span::new(0, 11, 1, 1),
+                        },
+                    },
+                    span: Span::new(0, 12, 1, 1),
+                }],
+                span: Span::new(0, 12, 1, 1),
+            })
+        );
     }
 
-    /// Statement
-    ///   : ExpressionStatement
-    ///   | BlockStatement
-    ///   | EmptyStatement
-    ///   ;
-    fn statement(&mut self) -> Option<Literal> {
-        if self.lookahead.clone()?.literal_type == LiteralType::Type(String::from("{")) {
-            self.block_statement()
-        } else {
-            self.expression_statement()
-        }
+    #[test]
+    #[should_panic(expected = "infix operator symbols must not be empty")]
+    fn test_empty_custom_operator() {
+        Parser::with_operators(vec![InfixOperator::custom("", 7, Associativity::Left)]);
     }
 
-    /// BlockStatement
-    ///  : '{' OptStatementList '}'
-    ///  ;
-    fn block_statement(&mut self) -> Option<Literal> {
-        self.eat(LiteralType::Type(String::from("{")))
-            .expect("Should have been able to eat '{'!");
+    #[test]
+    fn test_custom_operator_prefix_of_builtin() {
+        let mut parser =
+            Parser::with_operators(vec![InfixOperator::custom("&", 3, Associativity::Left)]);
 
-        let mut body = vec![];
-        if self.lookahead.clone()?.literal_type != LiteralType::Type(String::from("}")) {
-            body = self.statement_list(Some(Box::new(LiteralValue::Value(String::from("}")))));
-        }
+        let program: String = String::from("a & b && c;");
 
-        self.eat(LiteralType::Type(String::from("}")))
-            .expect("Should have been able to eat '}'!");
+        let ast = parser.parse(program);
 
-        Some(Literal {
-            literal_type: LiteralType::Type(String::from("BlockStatement")),
-            value: Box::new(LiteralValue::NestedValueList(body)),
-        })
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ExpressionStatement {
+                        expression: Expression {
+                            kind: ExpressionKind::LogicalExpression {
+                                operator: LogicalOp::And,
+                                left: Box::new(Expression {
+                                    kind: ExpressionKind::BinaryExpression {
+                                        operator: BinaryOp::Custom("&"),
+                                        left: Box::new(reference("a", 0)),
+                                        right: Box::new(reference("b", 4)),
+                                    },
+                                    span: Span::new(0, 5, 1, 1),
+                                }),
+                                right: Box::new(reference("c", 9)),
+                            },
+                            span: Span::new(0, 10, 1, 1),
+                        },
+                    },
+                    span: Span::new(0, 11, 1, 1),
+                }],
+                span: Span::new(0, 11, 1, 1),
+            })
+        );
     }
 
-    /// ExpressionStatement
-    ///   : Expression ';'
-    ///   ;
-    fn expression_statement(&mut self) -> Option<Literal> {
-        let expression = self.expression();
-        self.eat(LiteralType::Type(String::from(";")))
-            .expect("Should have been able to eat ';'!");
+    #[test]
+    fn test_custom_word_operator_as_property_name() {
+        let mut parser =
+            Parser::with_operators(vec![InfixOperator::custom("in", 4, Associativity::Left)]);
 
-        Some(Literal {
-            literal_type: LiteralType::Type(String::from("ExpressionStatement")),
-            value: Box::new(LiteralValue::NestedValue(expression)),
-        })
-    }
+        let program: String = String::from("a in b.in;");
 
-    /// Expression
-    ///   ; Literal
-    ///   ;
-    fn expression(&mut self) -> Option<Literal> {
-        self.additive_expression()
-    }
+        let ast = parser.parse(program);
 
-    /// Additive Expression
-    ///   : MultiplicativeExpression
-    ///   | AdditiveExpression ADDITIVE_OPERATOR MultiplicativeExpression
-    ///   ;
-    fn additive_expression(&mut self) -> Option<Literal> {
-        self.binary_expression(String::from("ADDITIVE_OPERATOR"))
-    }
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ExpressionStatement {
+                        expression: Expression {
+                            kind: ExpressionKind::BinaryExpression {
+                                operator: BinaryOp::Custom("in"),
+                                left: Box::new(reference("a", 0)),
+                                right: Box::new(Expression {
+                                    kind: ExpressionKind::MemberExpression {
+                                        object: Box::new(reference("b", 5)),
+                                        property: Box::new(reference("in", 7)),
+                                        computed: false,
+                                    },
+                                    span: Span::new(5, 9, 1, 6),
+                                }),
+                            },
+                            span: Span::new(0, 9, 1, 1),
+                        },
+                    },
+                    span: Span::new(0, 10, 1, 1),
+                }],
+                span: Span::new(0, 10, 1, 1),
+            })
+        );
 
-    /// Multiplicative Expression
-    ///   : MultiplicativeExpression
-    ///   | MultiplicativeExpression MULTIPLICATIVE_OPERATOR PrimaryExpression
-    ///   ;
-    fn multiplicative_expression(&mut self) -> Option<Literal> {
-        self.binary_expression(String::from("MULTIPLICATIVE_OPERATOR"))
+        let ast = parser.parse(String::from("x = { in: 1 }; class A { in() {} } index;"));
+
+        assert!(ast.is_ok(), "{:?}", ast);
     }
 
-    /// Generic binary expression.
-    fn binary_expression(&mut self, operator_token: String) -> Option<Literal> {
-        let mut left = match operator_token.as_str() {
-            "ADDITIVE_OPERATOR" => self.multiplicative_expression(),
-            "MULTIPLICATIVE_OPERATOR" => self.primary_expression(),
-            _ => None
-        };
+    #[test]
+    fn test_overridden_operator_precedence() {
+        let mut parser = Parser::with_operators(vec![InfixOperator {
+            symbol: "+",
+            precedence: 7,
+            associativity: Associativity::Left,
+            kind: InfixKind::Binary(BinaryOp::Add),
+        }]);
 
-        while self.lookahead.clone()?.literal_type
-            == LiteralType::Type(operator_token.clone())
-        {
-            let operator = self
-                .eat(LiteralType::Type(operator_token.clone()))
-                .expect(format!("Should have been able to eat '{}'!", operator_token).as_str());
-            let right = match operator_token.as_str() {
-                "ADDITIVE_OPERATOR" => self.multiplicative_expression(),
-                "MULTIPLICATIVE_OPERATOR" => self.primary_expression(),
-                _ => None
-            };
+        let program: String = String::from("2 * 3 + 4;");
 
-            left = Some(Literal {
-                literal_type: LiteralType::Type(String::from("BinaryExpression")),
-                value: Box::new(LiteralValue::NestedValueList(vec![
-                    Some(Literal {
-                        literal_type: LiteralType::Type(String::from("Left")),
-                        value: left?.value,
-                    }),
-                    Some(Literal {
-                        literal_type: LiteralType::Type(String::from("Operator")),
-                        value: operator.value,
-                    }),
-                    Some(Literal {
-                        literal_type: LiteralType::Type(String::from("Right")),
-                        value: right?.value,
-                    }),
-                ])),
-            });
-        }
+        let ast = parser.parse(program);
 
-        left
+        assert_eq!(
+            ast,
+            Ok(Program {
+                body: vec![Statement {
+                    kind: StatementKind::ExpressionStatement {
+                        expression: Expression {
+                            kind: ExpressionKind::BinaryExpression {
+                                operator: BinaryOp::Multiply,
+                                left: Box::new(number("2", 2.0, 0)),
+                                right: Box::new(Expression {
+                                    kind: ExpressionKind::BinaryExpression {
+                                        operator: BinaryOp::Add,
+                                        left: Box::new(number("3", 3.0, 4)),
+                                        right: Box::new(number("4", 4.0, 8)),
+                                    },
+                                    span: Span::new(4, 9, 1, 5),
+                                }),
+                            },
+                            span: Span::new(0, 9, 1, 1),
+                        },
+                    },
+                    span: Span::new(0, 10, 1, 1),
+                }],
+                span: Span::new(0, 10, 1, 1),
+            })
+        );
     }
 
-    /// Primary Expression
-    ///   : Literal
-    ///   | ParenthesisedExpression
-    ///   ;
-    fn primary_expression(&mut self) -> Option<Literal> {
-        match self.lookahead.clone()?.literal_type {
-            LiteralType::Type(ref string) => match string.as_str() {
-                "(" => self.parenthesised_expression(),
-                _ => self.literal()
-            }
-        }
-    }
+    #[test]
+    fn test_missing_semicolon() {
+        let mut parser = Parser::new();
 
-    /// Parenthesised Expression
-    ///   : '(' Expression ')'
-    ///   ;
-    fn parenthesised_expression(&mut self) -> Option<Literal> {
-        self.eat(LiteralType::Type(String::from("(")))
-            .expect("Should have been able to eat '('!");
-        let expression = self.expression();
-        self.eat(LiteralType::Type(String::from(")")))
-            .expect("Should have been able to eat ')'!");
-        expression
-    }
+        let program: String = String::from("(42) )");
 
-    /// Numeric Literal
-    ///   : NUMBER
-    ///   ;
-    fn numeric_literal(&mut self) -> Literal {
-        match self.eat(LiteralType::Type(String::from("NUMBER"))) {
-            Ok(token) => Literal {
-                literal_type: LiteralType::Type(String::from("NumericLiteral")),
-                value: token.value,
-            },
+        let ast = parser.parse(program);
 
-            Err(err) => panic!("{}", err),
-        }
+        assert_eq!(
+            ast,
+            Err(ParseError {
+                kind: ParseErrorKind::UnexpectedToken(String::from(")")),
+                expected: vec![TokenKind::Semicolon],
+                location: Span::new(5, 6, 1, 6),
+            })
+        );
     }
 
-    /// String Literal
-    ///   : STRING
-    ///   ;
-    fn string_literal(&mut self) -> Literal {
-        match self.eat(LiteralType::Type(String::from("STRING"))) {
-            Ok(token) => Literal {
-                literal_type: LiteralType::Type(String::from("StringLiteral")),
-                value: token.value,
-            },
-
-            Err(err) => panic!("{}", err),
-        }
-    }
+    #[test]
+    fn test_conditional_missing_colon() {
+        let mut parser = Parser::new();
 
-    fn eat(&mut self, token_type: LiteralType) -> Result<Literal, String> {
-        if let Some(token) = self.lookahead.clone() {
-            if token.literal_type != token_type {
-                return Err(format!(
-                    "Unexpected token: {:?}, expected: {:?}",
-                    token.value, token_type
-                ));
-            }
-            // Advance to next token
-            self.lookahead = self.tokenizer.get_next_token();
+        let program: String = String::from("a ? b;");
 
-            return Ok(token);
-        }
+        let ast = parser.parse(program);
 
-        Err(format!(
-            "Unexpected end of input, expected: {:?}",
-            token_type
-        ))
+        assert_eq!(
+            ast,
+            Err(ParseError {
+                kind: ParseErrorKind::UnexpectedToken(String::from(";")),
+                expected: vec![TokenKind::Colon],
+                location: Span::new(5, 6, 1, 6),
+            })
+        );
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::parser::*;
 
     #[test]
-    fn test_statement_lists() {
+    fn test_bare_super() {
         let mut parser = Parser::new();
 
-        let program: String = String::from(
-            r#"
-            // Program
-            /*
-                Multiline comments...
-            */
-            "hello";
-            42;
-            "#,
-        );
+        let program: String = String::from("super;");
 
         let ast = parser.parse(program);
 
         assert_eq!(
             ast,
-            Some(Literal {
-                literal_type: LiteralType::Type(String::from("Program")),
-                value: Box::new(LiteralValue::NestedValueList(vec![
-                    Some(Literal {
-                        literal_type: LiteralType::Type(String::from("ExpressionStatement")),
-                        value: Box::new(LiteralValue::NestedValue(Some(Literal {
-                            literal_type: LiteralType::Type(String::from("StringLiteral")),
-                            value: Box::new(LiteralValue::Value(String::from("\"hello\"")))
-                        })))
-                    }),
-                    Some(Literal {
-                        literal_type: LiteralType::Type(String::from("ExpressionStatement")),
-                        value: Box::new(LiteralValue::NestedValue(Some(Literal {
-                            literal_type: LiteralType::Type(String::from("NumericLiteral")),
-                            value: Box::new(LiteralValue::Value(String::from("42")))
-                        })))
-                    })
-                ]))
+            Err(ParseError {
+                kind: ParseErrorKind::UnexpectedToken(String::from(";")),
+                expected: vec![TokenKind::OpenParen, TokenKind::Dot, TokenKind::OpenBracket],
+                location: Span::new(5, 6, 1, 6),
             })
         );
-        dbg!(ast);
     }
 
     #[test]
-    fn test_string_literals() {
+    fn test_malformed_number() {
         let mut parser = Parser::new();
 
-        let program: String = String::from(
-            r#"
-            "hello";
-            "#,
-        );
+        let program: String = String::from("x = 0x;");
 
         let ast = parser.parse(program);
 
         assert_eq!(
             ast,
-            Some(Literal {
-                literal_type: LiteralType::Type(String::from("Program")),
-                value: Box::new(LiteralValue::NestedValueList(vec![Some(Literal {
-                    literal_type: LiteralType::Type(String::from("ExpressionStatement")),
-                    value: Box::new(LiteralValue::NestedValue(Some(Literal {
-                        literal_type: LiteralType::Type(String::from("StringLiteral")),
-                        value: Box::new(LiteralValue::Value(String::from("\"hello\"")))
-                    })))
-                })]))
+            Err(ParseError {
+                kind: ParseErrorKind::Lex(LexErrorKind::MalformedNumber(String::from("0x"))),
+                expected: vec![],
+                location: Span::new(4, 6, 1, 5),
             })
         );
-        dbg!(ast);
     }
 
     #[test]
-    fn test_numeric_literals() {
+    fn test_unexpected_end_of_input() {
         let mut parser = Parser::new();
 
-        let program: String = String::from(
-            r#"
-            42;
-            "#,
-        );
+        let program: String = String::from("{ 42;");
 
         let ast = parser.parse(program);
 
         assert_eq!(
             ast,
-            Some(Literal {
-                literal_type: LiteralType::Type(String::from("Program")),
-                value: Box::new(LiteralValue::NestedValueList(vec![Some(Literal {
-                    literal_type: LiteralType::Type(String::from("ExpressionStatement")),
-                    value: Box::new(LiteralValue::NestedValue(Some(Literal {
-                        literal_type: LiteralType::Type(String::from("NumericLiteral")),
-                        value: Box::new(LiteralValue::Value(String::from("42")))
-                    })))
-                })]))
+            Err(ParseError {
+                kind: ParseErrorKind::UnexpectedEof,
+                expected: vec![TokenKind::CloseBrace],
+                location: Span::new(5, 5, 1, 6),
             })
         );
-        dbg!(ast);
     }
 
     #[test]
-    fn test_blocks() {
+    fn test_unknown_character() {
         let mut parser = Parser::new();
 
-        let program: String = String::from(
-            r#"
-            {
-              42;
-              "hello";
-            }
-            "#,
-        );
+        let program: String = String::from("42 @ 2;");
 
         let ast = parser.parse(program);
 
         assert_eq!(
             ast,
-            Some(Literal {
-                literal_type: LiteralType::Type(String::from("Program")),
-                value: Box::new(LiteralValue::NestedValueList(vec![Some(Literal {
-                    literal_type: LiteralType::Type(String::from("BlockStatement")),
-                    value: Box::new(LiteralValue::NestedValueList(vec![
-                        Some(Literal {
-                            literal_type: LiteralType::Type(String::from("ExpressionStatement")),
-                            value: Box::new(LiteralValue::NestedValue(Some(Literal {
-                                literal_type: LiteralType::Type(String::from("NumericLiteral")),
-                                value: Box::new(LiteralValue::Value(String::from("42")))
-                            })))
-                        }),
-                        Some(Literal {
-                            literal_type: LiteralType::Type(String::from("ExpressionStatement")),
-                            value: Box::new(LiteralValue::NestedValue(Some(Literal {
-                                literal_type: LiteralType::Type(String::from("StringLiteral")),
-                                value: Box::new(LiteralValue::Value(String::from("\"hello\"")))
-                            })))
-                        })
-                    ]))
-                })]))
+            Err(ParseError {
+                kind: ParseErrorKind::Lex(LexErrorKind::UnknownCharacter('@')),
+                expected: vec![],
+                location: Span::new(3, 4, 1, 4),
             })
         );
-        dbg!(ast);
     }
 
     #[test]
-    fn test_empty_block() {
+    fn test_error_recovery() {
         let mut parser = Parser::new();
 
-        let program: String = String::from(
-            r#"
-            {
-              // ...
-            }
-            "#,
-        );
+        let program: String = String::from("1 + ;\n{ 2 3; }\n4;");
 
-        let ast = parser.parse(program);
+        let (ast, diagnostics) = parser.parse_with_diagnostics(program);
 
         assert_eq!(
             ast,
-            Some(Literal {
-                literal_type: LiteralType::Type(String::from("Program")),
-                value: Box::new(LiteralValue::NestedValueList(vec![Some(Literal {
-                    literal_type: LiteralType::Type(String::from("BlockStatement")),
-                    value: Box::new(LiteralValue::NestedValueList(vec![]))
-                })]))
-            })
+            Program {
+                body: vec![
+                    Statement {
+                        kind: StatementKind::Error,
+                        span: Span::new(0, 5, 1, 1),
+                    },
+                    Statement {
+                        kind: StatementKind::BlockStatement {
+                            body: vec![Statement {
+                                kind: StatementKind::Error,
+                                span: Span::new(8, 12, 2, 3),
+                            }],
+                        },
+                        span: Span::new(6, 14, 2, 1),
+                    },
+                    Statement {
+                        kind: StatementKind::ExpressionStatement {
+                            expression: Expression {
+                                kind: ExpressionKind::NumericLiteral {
+                                    raw: String::from("4"),
+                                    value: 4.0,
+                                },
+                                span: Span::new(15, 16, 3, 1),
+                            },
+                        },
+                        span: Span::new(15, 17, 3, 1),
+                    },
+                ],
+                span: Span::new(0, 17, 1, 1),
+            }
+        );
+        assert_eq!(
+            diagnostics,
+            vec![
+                ParseError {
+                    kind: ParseErrorKind::ExpectedExpression(Some(String::from(";"))),
+                    expected: vec![],
+                    location: Span::new(4, 5, 1, 5),
+                },
+                ParseError {
+                    kind: ParseErrorKind::UnexpectedToken(String::from("3")),
+                    expected: vec![TokenKind::Semicolon],
+                    location: Span::new(10, 11, 2, 5),
+                },
+            ]
         );
-        dbg!(ast);
     }
 
     #[test]
-    fn test_nested_blocks() {
+    fn test_error_recovery_unbalanced_braces() {
         let mut parser = Parser::new();
 
-        let program: String = String::from(
-            r#"
-            {
-              42;
-              {
-                "hello";
-              }
+        let program: String = String::from("} @ { 1;");
+
+        let (ast, diagnostics) = parser.parse_with_diagnostics(program);
+
+        assert_eq!(
+            ast,
+            Program {
+                body: vec![
+                    Statement {
+                        kind: StatementKind::Error,
+                        span: Span::new(0, 1, 1, 1),
+                    },
+                    Statement {
+                        kind: StatementKind::Error,
+                        span: Span::new(2, 8, 1, 3),
+                    },
+                ],
+                span: Span::new(0, 8, 1, 1),
             }
-            "#,
         );
+        assert_eq!(
+            diagnostics,
+            vec![
+                ParseError {
+                    kind: ParseErrorKind::ExpectedExpression(Some(String::from("}"))),
+                    expected: vec![],
+                    location: Span::new(0, 1, 1, 1),
+                },
+                ParseError {
+                    kind: ParseErrorKind::Lex(LexErrorKind::UnknownCharacter('@')),
+                    expected: vec![],
+                    location: Span::new(2, 3, 1, 3),
+                },
+                ParseError {
+                    kind: ParseErrorKind::UnexpectedToken(String::from("{")),
+                    expected: vec![TokenKind::Semicolon],
+                    location: Span::new(4, 5, 1, 5),
+                },
+            ]
+        );
+    }
 
-        let ast = parser.parse(program);
+    #[test]
+    fn test_error_recovery_inside_object_literal() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("function f() { x = { a: , b: 1 }; return 1; }");
+
+        let (ast, diagnostics) = parser.parse_with_diagnostics(program);
 
         assert_eq!(
             ast,
-            Some(Literal {
-                literal_type: LiteralType::Type(String::from("Program")),
-                value: Box::new(LiteralValue::NestedValueList(vec![Some(Literal {
-                    literal_type: LiteralType::Type(String::from("BlockStatement")),
-                    value: Box::new(LiteralValue::NestedValueList(vec![
-                        Some(Literal {
-                            literal_type: LiteralType::Type(String::from("ExpressionStatement")),
-                            value: Box::new(LiteralValue::NestedValue(Some(Literal {
-                                literal_type: LiteralType::Type(String::from("NumericLiteral")),
-                                value: Box::new(LiteralValue::Value(String::from("42")))
-                            })))
+            Program {
+                body: vec![Statement {
+                    kind: StatementKind::FunctionDeclaration {
+                        id: identifier("f", 9),
+                        params: vec![],
+                        body: Box::new(Statement {
+                            kind: StatementKind::BlockStatement {
+                                body: vec![
+                                    Statement {
+                                        kind: StatementKind::Error,
+                                        span: Span::new(15, 33, 1, 16),
+                                    },
+                                    Statement {
+                                        kind: StatementKind::ReturnStatement {
+                                            argument: Some(number("1", 1.0, 41)),
+                                        },
+                                        span: Span::new(34, 43, 1, 35),
+                                    },
+                                ],
+                            },
+                            span: Span::new(13, 45, 1, 14),
                         }),
-                        Some(Literal {
-                            literal_type: LiteralType::Type(String::from("BlockStatement")),
-                            value: Box::new(LiteralValue::NestedValueList(vec![Some(Literal {
-                                literal_type: LiteralType::Type(String::from(
-                                    "ExpressionStatement"
-                                )),
-                                value: Box::new(LiteralValue::NestedValue(Some(Literal {
-                                    literal_type: LiteralType::Type(String::from("StringLiteral")),
-                                    value: Box::new(LiteralValue::Value(String::from("\"hello\"")))
-                                })))
-                            })]))
-                        })
-                    ]))
-                })]))
-            })
+                    },
+                    span: Span::new(0, 45, 1, 1),
+                }],
+                span: Span::new(0, 45, 1, 1),
+            }
+        );
+        assert_eq!(
+            diagnostics,
+            vec![ParseError {
+                kind: ParseErrorKind::ExpectedExpression(Some(String::from(","))),
+                expected: vec![],
+                location: Span::new(24, 25, 1, 25),
+            }]
         );
-        dbg!(ast);
     }
 
     #[test]
-    fn test_math() {
+    fn test_error_recovery_inside_class_body() {
         let mut parser = Parser::new();
 
-        let program: String = String::from(
-            r#"
-            2 + 2 * 2;
-            "#,
+        let program: String = String::from("class A { m( { } } w;");
+
+        let (ast, diagnostics) = parser.parse_with_diagnostics(program);
+
+        assert_eq!(
+            ast,
+            Program {
+                body: vec![Statement {
+                    kind: StatementKind::Error,
+                    span: Span::new(0, 21, 1, 1),
+                }],
+                span: Span::new(0, 21, 1, 1),
+            }
         );
+        assert_eq!(
+            diagnostics,
+            vec![ParseError {
+                kind: ParseErrorKind::UnexpectedToken(String::from("{")),
+                expected: vec![TokenKind::Identifier],
+                location: Span::new(13, 14, 1, 14),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_nesting_too_deep() {
+        let mut parser = Parser::new();
+
+        let program: String = format!("{}1{};", "(".repeat(3000), ")".repeat(3000));
 
         let ast = parser.parse(program);
 
         assert_eq!(
             ast,
-            Some(Literal {
-                literal_type: LiteralType::Type(String::from("Program")),
-                value: Box::new(LiteralValue::NestedValueList(vec![Some(Literal {
-                    literal_type: LiteralType::Type(String::from("ExpressionStatement")),
-                    value: Box::new(LiteralValue::NestedValue(Some(Literal {
-                        literal_type: LiteralType::Type(String::from("BinaryExpression")),
-                        value: Box::new(LiteralValue::NestedValueList(vec![
-                            Some(Literal {
-                                literal_type: LiteralType::Type(String::from("Left")),
-                                value: Box::new(LiteralValue::Value(String::from("2")))
-                            }),
-                            Some(Literal {
-                                literal_type: LiteralType::Type(String::from("Operator")),
-                                value: Box::new(LiteralValue::Value(String::from("+")))
-                            }),
-                            Some(Literal {
-                                literal_type: LiteralType::Type(String::from("Right")),
-                                value: Box::new(LiteralValue::NestedValueList(vec![
-                                    Some(Literal {
-                                        literal_type: LiteralType::Type(String::from("Left")),
-                                        value: Box::new(LiteralValue::Value(String::from("2")))
-                                    }),
-                                    Some(Literal {
-                                        literal_type: LiteralType::Type(String::from("Operator")),
-                                        value: Box::new(LiteralValue::Value(String::from("*")))
-                                    }),
-                                    Some(Literal {
-                                        literal_type: LiteralType::Type(String::from("Right")),
-                                        value: Box::new(LiteralValue::Value(String::from("2")))
-                                    }),
-                                ]))
-                            })
-                        ]))
-                    })))
-                })]))
+            Err(ParseError {
+                kind: ParseErrorKind::NestingTooDeep,
+                expected: vec![],
+                location: Span::new(63, 64, 1, 64),
             })
         );
-        dbg!(ast);
     }
 
     #[test]
-    fn test_op_precedence() {
+    fn test_error_recovery_malformed_token() {
         let mut parser = Parser::new();
 
-        let program: String = String::from(
-            r#"
-            (2 + 2) * 2;
-            "#,
+        let program: String = String::from("f(1e);");
+
+        let (ast, diagnostics) = parser.parse_with_diagnostics(program);
+
+        assert_eq!(
+            ast,
+            Program {
+                body: vec![Statement {
+                    kind: StatementKind::ExpressionStatement {
+                        expression: Expression {
+                            kind: ExpressionKind::CallExpression {
+                                callee: Box::new(reference("f", 0)),
+                                arguments: vec![Expression {
+                                    kind: ExpressionKind::Error,
+                                    span: Span::new(2, 4, 1, 3),
+                                }],
+                            },
+                            span: Span::new(0, 5, 1, 1),
+                        },
+                    },
+                    span: Span::new(0, 6, 1, 1),
+                }],
+                span: Span::new(0, 6, 1, 1),
+            }
+        );
+        assert_eq!(
+            diagnostics,
+            vec![ParseError {
+                kind: ParseErrorKind::Lex(LexErrorKind::MalformedNumber(String::from("1e"))),
+                expected: vec![],
+                location: Span::new(2, 4, 1, 3),
+            }]
         );
+    }
 
-        let ast = parser.parse(program);
+    #[test]
+    fn test_error_recovery_unterminated_string() {
+        let mut parser = Parser::new();
+
+        let program: String = String::from("x = \"abc;");
+
+        let (ast, diagnostics) = parser.parse_with_diagnostics(program);
 
         assert_eq!(
             ast,
-            Some(Literal {
-                literal_type: LiteralType::Type(String::from("Program")),
-                value: Box::new(LiteralValue::NestedValueList(vec![Some(Literal {
-                    literal_type: LiteralType::Type(String::from("ExpressionStatement")),
-                    value: Box::new(LiteralValue::NestedValue(Some(Literal {
-                        literal_type: LiteralType::Type(String::from("BinaryExpression")),
-                        value: Box::new(LiteralValue::NestedValueList(vec![
-                            Some(Literal {
-                                literal_type: LiteralType::Type(String::from("Left")),
-                                value: Box::new(LiteralValue::NestedValueList(vec![
-                                    Some(Literal {
-                                        literal_type: LiteralType::Type(String::from("Left")),
-                                        value: Box::new(LiteralValue::Value(String::from("2")))
-                                    }),
-                                    Some(Literal {
-                                        literal_type: LiteralType::Type(String::from("Operator")),
-                                        value: Box::new(LiteralValue::Value(String::from("+")))
-                                    }),
-                                    Some(Literal {
-                                        literal_type: LiteralType::Type(String::from("Right")),
-                                        value: Box::new(LiteralValue::Value(String::from("2")))
-                                    }),
-                                ]))
-                            }),
-                            Some(Literal {
-                                literal_type: LiteralType::Type(String::from("Operator")),
-                                value: Box::new(LiteralValue::Value(String::from("*")))
-                            }),
-                            Some(Literal {
-                                literal_type: LiteralType::Type(String::from("Right")),
-                                value: Box::new(LiteralValue::Value(String::from("2")))
-                            }),
-                        ]))
-                    })))
-                })]))
-            })
+            Program {
+                body: vec![Statement {
+                    kind: StatementKind::Error,
+                    span: Span::new(0, 9, 1, 1),
+                }],
+                span: Span::new(0, 9, 1, 1),
+            }
+        );
+        assert_eq!(
+            diagnostics,
+            vec![ParseError {
+                kind: ParseErrorKind::Lex(LexErrorKind::UnterminatedString),
+                expected: vec![],
+                location: Span::new(4, 9, 1, 5),
+            }]
         );
-        dbg!(ast);
     }
 }
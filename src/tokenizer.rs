@@ -1,93 +1,704 @@
-/// Tokenizer.
-///
-/// Lazily pulls a token from a stream.
+//! Tokenizer.
+//!
+//! Lazily pulls a token from a stream.
+
+use std::fmt;
+use std::sync::OnceLock;
 
-use crate::parser::{Literal, LiteralType, LiteralValue};
-use regex::Regex;
+use crate::error::{LexError, LexErrorKind};
+use regex_automata::meta::Regex;
+use regex_automata::{Anchored, Input};
 
-const SPEC: [(&str, Option<&str>); 12] = [
+/// Token rules, matched at the cursor; the first one to match wins.
+const SPEC: [(&str, Option<TokenKind>); 27] = [
     // Skip whitespaces
     (r"^\s+", None),
 
     // Skip single-line comments
     (r"^\/\/.*", None),
 
-    // Skip multi-line comments
-    (r"^\/\*[\s\S]*?\*\/", None),
+    // Skip multi-line comments. An unterminated one runs to the end of
+    // the input and is reported by `get_next_token`.
+    (r"^\/\*[\s\S]*?(?:\*\/|\z)", None),
+
+    // Identifiers and keywords, told apart through `KEYWORDS`
+    (r"^[A-Za-z_$][\w$]*", Some(TokenKind::Identifier)),
+
+    // Numbers: hex, octal, binary, then decimals with an optional
+    // fraction and exponent. Digits may be separated by single `_`.
+    // A leading `0` stands alone, so legacy octal `017` and `0_1` are
+    // malformed, as in strict-mode JavaScript. Ahead of `.` so `.5` is
+    // a number.
+    (
+        concat!(
+            r"^(0[xX][\da-fA-F](_?[\da-fA-F])*",
+            r"|0[oO][0-7](_?[0-7])*",
+            r"|0[bB][01](_?[01])*",
+            r"|((0|[1-9](_?\d)*)(\.(\d(_?\d)*)?)?|\.\d(_?\d)*)([eE][+\-]?\d(_?\d)*)?)",
+        ),
+        Some(TokenKind::Number),
+    ),
 
     // Symbols, Delimiters
-    (r"^;", Some(";")),
-    (r"^\{", Some("{")),
-    (r"^\}", Some("}")),
-    (r"^\(", Some("(")),
-    (r"^\)", Some(")")),
+    (r"^;", Some(TokenKind::Semicolon)),
+    (r"^\{", Some(TokenKind::OpenBrace)),
+    (r"^\}", Some(TokenKind::CloseBrace)),
+    (r"^\(", Some(TokenKind::OpenParen)),
+    (r"^\)", Some(TokenKind::CloseParen)),
+    (r"^,", Some(TokenKind::Comma)),
+    (r"^\.", Some(TokenKind::Dot)),
+    (r"^\[", Some(TokenKind::OpenBracket)),
+    (r"^\]", Some(TokenKind::CloseBracket)),
+    (r"^\?", Some(TokenKind::QuestionMark)),
+    (r"^:", Some(TokenKind::Colon)),
+
+    // Arrow, ahead of `=` so it is not split
+    (r"^=>", Some(TokenKind::Arrow)),
+
+    // Equality operators ==, !=, ===, !==
+    (r"^[=!]==?", Some(TokenKind::EqualityOperator)),
+
+    // Assignment operators: =, *=, /=, +=, -=
+    (r"^=", Some(TokenKind::SimpleAssign)),
+    (r"^[*\/+\-]=", Some(TokenKind::ComplexAssign)),
 
     // Math operators +, -, *, /
-    (r"^[+\-]", Some("ADDITIVE_OPERATOR")),
-    (r"^[*\/]", Some("MULTIPLICATIVE_OPERATOR")),
+    (r"^[+\-]", Some(TokenKind::AdditiveOperator)),
+    (r"^[*\/]", Some(TokenKind::MultiplicativeOperator)),
 
-    // Numbers
-    (r"^\d+", Some("NUMBER")),
+    // Relational operators <, >, <=, >=
+    (r"^[<>]=?", Some(TokenKind::RelationalOperator)),
 
-    // Strings
-    (r#"^"[^"]*""#, Some("STRING"))
+    // Logical operators &&, ||, !
+    (r"^&&", Some(TokenKind::LogicalAnd)),
+    (r"^\|\|", Some(TokenKind::LogicalOr)),
+    (r"^!", Some(TokenKind::LogicalNot)),
+
+    // Strings. An unterminated one runs to the end of the input and is
+    // reported by `get_next_token`.
+    (r#"^"[^"]*"?"#, Some(TokenKind::String)),];
+
+/// Reserved words, matched as identifiers and then looked up here.
+const KEYWORDS: [(&str, TokenKind); 21] = [
+    ("let", TokenKind::Let),
+    ("const", TokenKind::Const),
+    ("var", TokenKind::Var),
+    ("if", TokenKind::If),
+    ("else", TokenKind::Else),
+    ("while", TokenKind::While),
+    ("do", TokenKind::Do),
+    ("for", TokenKind::For),
+    ("function", TokenKind::Function),
+    ("return", TokenKind::Return),
+    ("typeof", TokenKind::Typeof),
+    ("void", TokenKind::Void),
+    ("delete", TokenKind::Delete),
+    ("class", TokenKind::Class),
+    ("extends", TokenKind::Extends),
+    ("this", TokenKind::This),
+    ("super", TokenKind::Super),
+    ("new", TokenKind::New),
+    ("true", TokenKind::True),
+    ("false", TokenKind::False),
+    ("null", TokenKind::Null),
 ];
 
+/// `SPEC` compiled once into a single matcher shared by every tokenizer.
+///
+/// All entries are tried in one leftmost-first pass over the input,
+/// which reports the first entry that matches and where its match ends.
+fn spec() -> &'static Regex {
+    static COMPILED: OnceLock<Regex> = OnceLock::new();
+    COMPILED.get_or_init(|| Regex::new_many(&SPEC.map(|(reg, _)| reg)).unwrap())
+}
+
+/// Location of a token or node in the source.
+///
+/// `start` and `end` are byte offsets, `line` and `column` are the
+/// 1-based position of `start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize, line: usize, column: usize) -> Self {
+        Self {
+            start,
+            end,
+            line,
+            column,
+        }
+    }
+
+    /// Span covering from the start of `self` to the end of `other`.
+    pub fn to(&self, other: &Span) -> Span {
+        Span {
+            end: other.end,
+            ..*self
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Semicolon,
+    OpenBrace,
+    CloseBrace,
+    OpenParen,
+    CloseParen,
+    Comma,
+    Dot,
+    OpenBracket,
+    CloseBracket,
+    QuestionMark,
+    Colon,
+    Arrow,
+    Let,
+    Const,
+    Var,
+    If,
+    Else,
+    While,
+    Do,
+    For,
+    Function,
+    Return,
+    Typeof,
+    Void,
+    Delete,
+    Class,
+    Extends,
+    This,
+    Super,
+    New,
+    True,
+    False,
+    Null,
+    Identifier,
+    SimpleAssign,
+    ComplexAssign,
+    AdditiveOperator,
+    MultiplicativeOperator,
+    RelationalOperator,
+    EqualityOperator,
+    LogicalAnd,
+    LogicalOr,
+    LogicalNot,
+    /// Infix operator registered with `Tokenizer::with_operators`.
+    CustomOperator,
+    Number,
+    String,
+    /// Input that failed to lex. Never produced by `Tokenizer`, which
+    /// returns a `LexError` instead; the parser substitutes it so the
+    /// malformed input keeps its place in the token stream.
+    Error,
+}
+
+impl TokenKind {
+    /// Whether this is a reserved word such as `let` or `delete`.
+    pub fn is_keyword(&self) -> bool {
+        KEYWORDS.iter().any(|(_, kind)| kind == self)
+    }
+}
+
+impl fmt::Display for TokenKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TokenKind::Semicolon => "';'",
+            TokenKind::OpenBrace => "'{'",
+            TokenKind::CloseBrace => "'}'",
+            TokenKind::OpenParen => "'('",
+            TokenKind::CloseParen => "')'",
+            TokenKind::Comma => "','",
+            TokenKind::Dot => "'.'",
+            TokenKind::OpenBracket => "'['",
+            TokenKind::CloseBracket => "']'",
+            TokenKind::QuestionMark => "'?'",
+            TokenKind::Colon => "':'",
+            TokenKind::Arrow => "'=>'",
+            TokenKind::Let => "'let'",
+            TokenKind::Const => "'const'",
+            TokenKind::Var => "'var'",
+            TokenKind::If => "'if'",
+            TokenKind::Else => "'else'",
+            TokenKind::While => "'while'",
+            TokenKind::Do => "'do'",
+            TokenKind::For => "'for'",
+            TokenKind::Function => "'function'",
+            TokenKind::Return => "'return'",
+            TokenKind::Typeof => "'typeof'",
+            TokenKind::Void => "'void'",
+            TokenKind::Delete => "'delete'",
+            TokenKind::Class => "'class'",
+            TokenKind::Extends => "'extends'",
+            TokenKind::This => "'this'",
+            TokenKind::Super => "'super'",
+            TokenKind::New => "'new'",
+            TokenKind::True => "'true'",
+            TokenKind::False => "'false'",
+            TokenKind::Null => "'null'",
+            TokenKind::Identifier => "identifier",
+            TokenKind::SimpleAssign => "'='",
+            TokenKind::ComplexAssign => "compound assignment operator",
+            TokenKind::AdditiveOperator => "additive operator",
+            TokenKind::MultiplicativeOperator => "multiplicative operator",
+            TokenKind::RelationalOperator => "relational operator",
+            TokenKind::EqualityOperator => "equality operator",
+            TokenKind::LogicalAnd => "'&&'",
+            TokenKind::LogicalOr => "'||'",
+            TokenKind::LogicalNot => "'!'",
+            TokenKind::CustomOperator => "operator",
+            TokenKind::Number => "number",
+            TokenKind::String => "string",
+            TokenKind::Error => "malformed token",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub value: String,
+    pub span: Span,
+}
+
+/// Position in the input saved by `Tokenizer::checkpoint`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checkpoint {
+    cursor: usize,
+    line: usize,
+    column: usize,
+}
+
 pub struct Tokenizer {
-    pub string: LiteralType,
+    pub string: String,
     cursor: usize,
+    line: usize,
+    column: usize,
+    /// Extra operator symbols, longest first.
+    operators: Vec<&'static str>,
 }
 
 impl Tokenizer {
     /// Intializes a string.
     pub fn new(string: String) -> Self {
+        Self::with_operators(string, vec![])
+    }
+
+    /// Intializes a string, also recognising each of `operators` as a
+    /// `TokenKind::CustomOperator`.
+    ///
+    /// The longest match wins, between these and the built-in tokens, so
+    /// `in` does not split `index` and `&` does not split `&&`. On a tie
+    /// the operator wins, so `in` is not read as an identifier. Empty
+    /// symbols are ignored.
+    pub fn with_operators(string: String, mut operators: Vec<&'static str>) -> Self {
+        operators.retain(|symbol| !symbol.is_empty());
+        operators.sort_by_key(|symbol| std::cmp::Reverse(symbol.len()));
         Self {
-            string: LiteralType::Type(string),
-            cursor: 0
+            string,
+            cursor: 0,
+            line: 1,
+            column: 1,
+            operators,
         }
     }
 
     /// Obtains next token.
-    pub fn get_next_token(&mut self) -> Option<Literal> {
-        if !self.has_more_tokens() {
-            return None;
-        }
+    ///
+    /// An unknown character is skipped after being reported, so
+    /// tokenizing can resume on the following call. An unterminated
+    /// comment or string is reported once and consumes the rest of the
+    /// input.
+    pub fn get_next_token(&mut self) -> Result<Option<Token>, LexError> {
+        while self.has_more_tokens() {
+            let start = self.current_span();
+            let Some((length, token_kind)) = self.get_match() else {
+                let character = self.string[self.cursor..].chars().next().unwrap();
+                self.advance(character.len_utf8());
+                return Err(LexError {
+                    kind: LexErrorKind::UnknownCharacter(character),
+                    location: start.to(&self.current_span()),
+                });
+            };
+            self.advance(length);
+
+            let text = &self.string[start.start..self.cursor];
+            let unterminated = match token_kind {
+                None if text.starts_with("/*") && (text.len() < 4 || !text.ends_with("*/")) => {
+                    Some(LexErrorKind::UnterminatedComment)
+                }
+                Some(TokenKind::String) if text.len() < 2 || !text.ends_with('"') => {
+                    Some(LexErrorKind::UnterminatedString)
+                }
+                _ => None,
+            };
+            if let Some(kind) = unterminated {
+                return Err(LexError {
+                    kind,
+                    location: start.to(&self.current_span()),
+                });
+            }
 
-        match self.string.clone() {
-            LiteralType::Type(string) => {
-                let string = string[self.cursor..].to_string();
-                for &(reg, token_type) in SPEC.iter() {
-                    if let Some(token_val) = self.get_match(Regex::new(reg).unwrap(),
-                                                            string.as_str()) {
-                        if token_type.is_none() {
-                            return self.get_next_token();
-                        }
-                        return Some(Literal {
-                            literal_type: LiteralType::Type(token_type?.to_string()),
-                            value: Box::new(LiteralValue::Value(token_val)),
+            // A number must not run straight into a digit or identifier, as
+            // in `017`, `0x` or `1_`, nor into another fraction, as in
+            // `1..2`; report the whole run as one error. A `.` before a
+            // name is a member access, as in `1.5.toFixed(2)`.
+            if token_kind == Some(TokenKind::Number) {
+                let rest = &self.string[self.cursor..];
+                let mut characters = rest.chars();
+                let runs_on = match characters.next() {
+                    Some('.') => characters.next().is_some_and(|next| next.is_ascii_digit()),
+                    Some(character) => character.is_alphanumeric() || "_$".contains(character),
+                    None => false,
+                };
+                if runs_on {
+                    let run = rest
+                        .find(|character: char| {
+                            !(character.is_alphanumeric() || "_$.".contains(character))
                         })
-                    }
+                        .unwrap_or(rest.len());
+                    self.advance(run);
+                    return Err(LexError {
+                        kind: LexErrorKind::MalformedNumber(
+                            self.string[start.start..self.cursor].to_string(),
+                        ),
+                        location: start.to(&self.current_span()),
+                    });
                 }
-                panic!("Unexpected token: {:?}", string.chars().nth(0));
+            }
+
+            if let Some(kind) = token_kind {
+                let value = &self.string[start.start..self.cursor];
+                let kind = match kind {
+                    TokenKind::Identifier => KEYWORDS
+                        .iter()
+                        .find(|(keyword, _)| *keyword == value)
+                        .map_or(kind, |&(_, keyword_kind)| keyword_kind),
+                    _ => kind,
+                };
+                return Ok(Some(Token {
+                    kind,
+                    value: value.to_string(),
+                    span: start.to(&self.current_span()),
+                }));
             }
         }
+
+        Ok(None)
     }
 
-    /// Matches a token for given regex.
-    fn get_match(&mut self, reg: Regex, string: &str) -> Option<String> {
-        if let Some(mat) = reg.captures(string) {
-            self.cursor += mat.get(0).unwrap().len();
-            return Some(mat.get(0).unwrap().as_str().to_string());
+    /// Saves the current position, to return to with `rewind`.
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            cursor: self.cursor,
+            line: self.line,
+            column: self.column,
         }
-        None
     }
 
-    /// Check if there exists more tokens.
-    fn has_more_tokens(&self) -> bool {
-        match self.string {
-            LiteralType::Type(ref string) => {
-                self.cursor < string.len()
+    /// Moves back to a position saved with `checkpoint`.
+    pub fn rewind(&mut self, checkpoint: Checkpoint) {
+        self.cursor = checkpoint.cursor;
+        self.line = checkpoint.line;
+        self.column = checkpoint.column;
+    }
+
+    /// Empty span at the current cursor position.
+    pub fn current_span(&self) -> Span {
+        Span::new(self.cursor, self.cursor, self.line, self.column)
+    }
+
+    /// Matches the first spec entry or the longest operator symbol at the
+    /// cursor, whichever is longer, returning the length of the match and
+    /// its token kind (`None` for skipped input).
+    fn get_match(&self) -> Option<(usize, Option<TokenKind>)> {
+        let string = &self.string[self.cursor..];
+        let input = Input::new(string).anchored(Anchored::Yes);
+        let builtin = spec()
+            .search(&input)
+            .map(|mat| (mat.end(), SPEC[mat.pattern().as_usize()].1));
+
+        match self.operators.iter().find(|symbol| string.starts_with(**symbol)) {
+            Some(symbol) if builtin.is_none_or(|(length, _)| symbol.len() >= length) => {
+                Some((symbol.len(), Some(TokenKind::CustomOperator)))
+            }
+            _ => builtin,
+        }
+    }
+
+    /// Moves the cursor `length` bytes forward, keeping line and column in sync.
+    fn advance(&mut self, length: usize) {
+        for character in self.string[self.cursor..self.cursor + length].chars() {
+            if character == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
             }
         }
+        self.cursor += length;
+    }
+
+    /// Check if there exists more tokens.
+    fn has_more_tokens(&self) -> bool {
+        self.cursor < self.string.len()
+    }
+}
+
+impl Iterator for Tokenizer {
+    type Item = Result<Token, LexError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.get_next_token().transpose()
+    }
+}
+
+/// Tokenizes a whole string, stopping at the first lexical error.
+pub fn tokenize(string: &str) -> Result<Vec<Token>, LexError> {
+    Tokenizer::new(string.to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{LexError, LexErrorKind};
+    use crate::tokenizer::*;
+
+    #[test]
+    fn test_tokenize() {
+        let tokens = tokenize("(1 + 2) // three");
+
+        assert_eq!(
+            tokens,
+            Ok(vec![
+                Token {
+                    kind: TokenKind::OpenParen,
+                    value: String::from("("),
+                    span: Span::new(0, 1, 1, 1),
+                },
+                Token {
+                    kind: TokenKind::Number,
+                    value: String::from("1"),
+                    span: Span::new(1, 2, 1, 2),
+                },
+                Token {
+                    kind: TokenKind::AdditiveOperator,
+                    value: String::from("+"),
+                    span: Span::new(3, 4, 1, 4),
+                },
+                Token {
+                    kind: TokenKind::Number,
+                    value: String::from("2"),
+                    span: Span::new(5, 6, 1, 6),
+                },
+                Token {
+                    kind: TokenKind::CloseParen,
+                    value: String::from(")"),
+                    span: Span::new(6, 7, 1, 7),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_iterator_resumes_after_error() {
+        let tokens: Vec<_> = Tokenizer::new(String::from("1 @\n2")).collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Ok(Token {
+                    kind: TokenKind::Number,
+                    value: String::from("1"),
+                    span: Span::new(0, 1, 1, 1),
+                }),
+                Err(LexError {
+                    kind: LexErrorKind::UnknownCharacter('@'),
+                    location: Span::new(2, 3, 1, 3),
+                }),
+                Ok(Token {
+                    kind: TokenKind::Number,
+                    value: String::from("2"),
+                    span: Span::new(4, 5, 2, 1),
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_keywords_and_identifiers() {
+        let source = "let letter const $var_1 true trueValue null nullable let$x true$";
+        let kinds: Vec<_> = tokenize(source)
+            .unwrap()
+            .into_iter()
+            .map(|token| token.kind)
+            .collect();
+
+        assert_eq!(
+            kinds,
+            vec![
+                TokenKind::Let,
+                TokenKind::Identifier,
+                TokenKind::Const,
+                TokenKind::Identifier,
+                TokenKind::True,
+                TokenKind::Identifier,
+                TokenKind::Null,
+                TokenKind::Identifier,
+                TokenKind::Identifier,
+                TokenKind::Identifier,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_custom_operators() {
+        let source = String::from("a ** b in index && c & d");
+        let tokens: Vec<_> = Tokenizer::with_operators(source, vec!["in", "**", "&"])
+            .map(|token| token.map(|token| (token.kind, token.value)))
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(
+            tokens,
+            vec![
+                (TokenKind::Identifier, String::from("a")),
+                (TokenKind::CustomOperator, String::from("**")),
+                (TokenKind::Identifier, String::from("b")),
+                (TokenKind::CustomOperator, String::from("in")),
+                (TokenKind::Identifier, String::from("index")),
+                (TokenKind::LogicalAnd, String::from("&&")),
+                (TokenKind::Identifier, String::from("c")),
+                (TokenKind::CustomOperator, String::from("&")),
+                (TokenKind::Identifier, String::from("d")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_numeric_literals() {
+        let values: Vec<_> = tokenize("3.14 1e9 .5 1.e-3 0xFF 0o17 0B1010 1_000_000")
+            .unwrap()
+            .into_iter()
+            .map(|token| (token.kind, token.value))
+            .collect();
+
+        assert_eq!(
+            values,
+            ["3.14", "1e9", ".5", "1.e-3", "0xFF", "0o17", "0B1010", "1_000_000"]
+                .iter()
+                .map(|value| (TokenKind::Number, value.to_string()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_malformed_numeric_literals() {
+        let tokens: Vec<_> = Tokenizer::new(String::from("1..2 0x 1__0 017 0_1 3")).collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Err(LexError {
+                    kind: LexErrorKind::MalformedNumber(String::from("1..2")),
+                    location: Span::new(0, 4, 1, 1),
+                }),
+                Err(LexError {
+                    kind: LexErrorKind::MalformedNumber(String::from("0x")),
+                    location: Span::new(5, 7, 1, 6),
+                }),
+                Err(LexError {
+                    kind: LexErrorKind::MalformedNumber(String::from("1__0")),
+                    location: Span::new(8, 12, 1, 9),
+                }),
+                Err(LexError {
+                    kind: LexErrorKind::MalformedNumber(String::from("017")),
+                    location: Span::new(13, 16, 1, 14),
+                }),
+                Err(LexError {
+                    kind: LexErrorKind::MalformedNumber(String::from("0_1")),
+                    location: Span::new(17, 20, 1, 18),
+                }),
+                Ok(Token {
+                    kind: TokenKind::Number,
+                    value: String::from("3"),
+                    span: Span::new(21, 22, 1, 22),
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_member_access_on_numbers() {
+        let tokens: Vec<_> = tokenize("1.5.toFixed(2) 1..x 0.5")
+            .unwrap()
+            .into_iter()
+            .map(|token| (token.kind, token.value))
+            .collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                (TokenKind::Number, String::from("1.5")),
+                (TokenKind::Dot, String::from(".")),
+                (TokenKind::Identifier, String::from("toFixed")),
+                (TokenKind::OpenParen, String::from("(")),
+                (TokenKind::Number, String::from("2")),
+                (TokenKind::CloseParen, String::from(")")),
+                (TokenKind::Number, String::from("1.")),
+                (TokenKind::Dot, String::from(".")),
+                (TokenKind::Identifier, String::from("x")),
+                (TokenKind::Number, String::from("0.5")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unterminated_comment() {
+        let tokens: Vec<_> = Tokenizer::new(String::from("1 /* a */ /* b\n/* c")).collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Ok(Token {
+                    kind: TokenKind::Number,
+                    value: String::from("1"),
+                    span: Span::new(0, 1, 1, 1),
+                }),
+                Err(LexError {
+                    kind: LexErrorKind::UnterminatedComment,
+                    location: Span::new(10, 19, 1, 11),
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unterminated_string() {
+        let tokens: Vec<_> = Tokenizer::new(String::from("x = \"abc;\ny;")).collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Ok(Token {
+                    kind: TokenKind::Identifier,
+                    value: String::from("x"),
+                    span: Span::new(0, 1, 1, 1),
+                }),
+                Ok(Token {
+                    kind: TokenKind::SimpleAssign,
+                    value: String::from("="),
+                    span: Span::new(2, 3, 1, 3),
+                }),
+                Err(LexError {
+                    kind: LexErrorKind::UnterminatedString,
+                    location: Span::new(4, 12, 1, 5),
+                }),
+            ]
+        );
     }
-}
\ No newline at end of file
+}